@@ -0,0 +1,227 @@
+//! Known answers per day and checking new runs against them.
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use crate::{error::Error, solution::Run};
+
+/// Known answers for a day, read from a file with one `partN: answer` per
+/// line. Blank lines and lines starting with `#` are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: BTreeMap<u8, String>,
+}
+
+impl Answers {
+    /// Parses the text of an answers file, the error says which line is wrong.
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut parts = BTreeMap::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let part = line
+                .strip_prefix("part")
+                .and_then(|rest| rest.split_once(':'))
+                .and_then(|(part, answer)| Some((part.trim().parse::<u8>().ok()?, answer.trim())));
+            match part {
+                Some((part, answer)) => {
+                    parts.insert(part, answer.to_owned());
+                }
+                None => {
+                    return Err(format!(
+                        "linea {}: se esperaba `partN: respuesta`",
+                        number + 1
+                    ))
+                }
+            }
+        }
+        Ok(Answers { parts })
+    }
+
+    /// Reads an answers file, a missing file means no answers yet.
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Answers::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The known answer of `part`, if any.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts.get(&part).map(String::as_str)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in &self.parts {
+            writeln!(f, "part{}: {}", part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// How a part compares against its known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer is the known one.
+    Pass,
+    /// The answer changed, or the solver failed.
+    Fail,
+    /// Nothing recorded for this part yet.
+    Missing,
+}
+
+/// Outcome of comparing one part against its known answer.
+#[derive(Debug, Clone)]
+pub struct Check {
+    /// Day of the puzzle.
+    pub day: u8,
+    /// Part of the puzzle, 1 or 2.
+    pub part: u8,
+    /// How the answer compares.
+    pub status: Status,
+    /// The known answer, `None` when it's missing.
+    pub expected: Option<String>,
+    /// What the solver gave this time.
+    pub actual: Result<String, Error>,
+}
+
+impl Check {
+    /// Expected against actual in diff style, only for failures.
+    pub fn diff(&self) -> Option<String> {
+        if self.status != Status::Fail {
+            return None;
+        }
+        let expected = self.expected.as_deref().unwrap_or("(sin respuesta)");
+        let actual = match &self.actual {
+            Ok(answer) => answer.clone(),
+            Err(error) => format!("error: {}", error),
+        };
+        Some(format!("- {}\n+ {}", expected, actual))
+    }
+}
+
+/// Compares every part of a run against the known answers. A failed parse
+/// fails every part that has an answer recorded.
+pub fn check(run: &Run, answers: &Answers, parts: &[u8]) -> Vec<Check> {
+    parts
+        .iter()
+        .map(|&part| {
+            let actual = match (&run.parse.result, run.parts.iter().find(|p| p.part == part)) {
+                (Err(error), _) => Err(error.clone()),
+                (Ok(()), Some(run)) => run.answer.result.clone(),
+                (Ok(()), None) => Err(Error::solve(run.day, "la parte no se ejecuto")),
+            };
+            let expected = answers.get(part).map(str::to_owned);
+            let status = match (&expected, &actual) {
+                (_, Err(_)) => Status::Fail,
+                (None, Ok(_)) => Status::Missing,
+                (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+                (Some(_), Ok(_)) => Status::Fail,
+            };
+            Check {
+                day: run.day,
+                part,
+                status,
+                expected,
+                actual,
+            }
+        })
+        .collect()
+}
+
+/// Fails every part of a day whose input or answers could not be read, so
+/// the other days can still be checked.
+pub fn unreadable(day: u8, error: &Error, parts: &[u8]) -> Vec<Check> {
+    parts
+        .iter()
+        .map(|&part| Check {
+            day,
+            part,
+            status: Status::Fail,
+            expected: None,
+            actual: Err(error.clone()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::solution::{PartRun, Timed};
+
+    fn run(parse: Result<(), Error>, answers: &[(u8, &str)]) -> Run {
+        Run {
+            day: 3,
+            parse: Timed {
+                result: parse,
+                time: Duration::ZERO,
+            },
+            parts: answers
+                .iter()
+                .map(|&(part, answer)| PartRun {
+                    part,
+                    answer: Timed {
+                        result: Ok(answer.to_owned()),
+                        time: Duration::ZERO,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# comentario\npart1: 42\n\npart2:  abc \n").unwrap();
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("abc"));
+        assert_eq!(answers.to_string(), "part1: 42\npart2: abc\n");
+        assert!(Answers::parse("respuesta 42").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1: 42").unwrap();
+        let checks = check(&run(Ok(()), &[(1, "42"), (2, "7")]), &answers, &[1, 2]);
+        assert_eq!(checks[0].status, Status::Pass);
+        assert_eq!(checks[1].status, Status::Missing);
+
+        let checks = check(&run(Ok(()), &[(1, "41")]), &answers, &[1]);
+        assert_eq!(checks[0].status, Status::Fail);
+        assert_eq!(checks[0].diff().unwrap(), "- 42\n+ 41");
+    }
+
+    #[test]
+    fn test_check_parse_error() {
+        let answers = Answers::parse("part1: 42").unwrap();
+        let checks = check(
+            &run(Err(Error::parse(3, "mal").at(1, 2)), &[]),
+            &answers,
+            &[1],
+        );
+        assert_eq!(checks[0].status, Status::Fail);
+        assert_eq!(
+            checks[0].diff().unwrap(),
+            "- 42\n+ error: day 3 parse error at line 1, column 2: mal"
+        );
+    }
+
+    #[test]
+    fn test_unreadable() {
+        let error = Error::read(3, "no existe");
+        let checks = unreadable(3, &error, &[1, 2]);
+        assert_eq!(checks.len(), 2);
+        assert!(checks.iter().all(|c| c.status == Status::Fail));
+        assert_eq!(
+            checks[1].diff().unwrap(),
+            "- (sin respuesta)\n+ error: day 3 read error: no existe"
+        );
+    }
+}
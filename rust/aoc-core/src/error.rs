@@ -0,0 +1,145 @@
+//! The error every day returns, with where in the run and the input it
+//! happened.
+
+use std::fmt;
+
+/// Where in a run something went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Loading the input file.
+    Read,
+    /// Turning the text into the day's input type.
+    Parse,
+    /// Computing an answer from the parsed input.
+    Solve,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Read => "read",
+            Stage::Parse => "parse",
+            Stage::Solve => "solve",
+        })
+    }
+}
+
+/// Error returned by every parser and solver, it says which day and stage
+/// failed and, when known, the 1-based line and column of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// Day of the puzzle, 1 to 25.
+    pub day: u8,
+    /// Stage the error comes from.
+    pub stage: Stage,
+    /// 1-based line of the input.
+    pub line: Option<usize>,
+    /// 1-based column of the input, in characters.
+    pub column: Option<usize>,
+    /// What went wrong, in Spanish like the rest of the messages.
+    pub message: String,
+}
+
+impl Error {
+    /// An error without a position in the input.
+    pub fn new(day: u8, stage: Stage, message: impl Into<String>) -> Self {
+        Error {
+            day,
+            stage,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error of the [`Stage::Read`] stage.
+    pub fn read(day: u8, message: impl Into<String>) -> Self {
+        Error::new(day, Stage::Read, message)
+    }
+
+    /// An error of the [`Stage::Parse`] stage.
+    pub fn parse(day: u8, message: impl Into<String>) -> Self {
+        Error::new(day, Stage::Parse, message)
+    }
+
+    /// An error of the [`Stage::Solve`] stage.
+    pub fn solve(day: u8, message: impl Into<String>) -> Self {
+        Error::new(day, Stage::Solve, message)
+    }
+
+    /// Points the error at a 1-based line and column.
+    pub fn at(self, line: usize, column: usize) -> Self {
+        Error {
+            line: Some(line),
+            column: Some(column),
+            ..self
+        }
+    }
+
+    /// Points the error at a 1-based line, keeping the column.
+    pub fn at_line(self, line: usize) -> Self {
+        Error {
+            line: Some(line),
+            ..self
+        }
+    }
+
+    /// Points the error at the start of `rest`, which must be a slice of
+    /// `input`.
+    pub fn at_rest(self, input: &str, rest: &str) -> Self {
+        match position(input, rest) {
+            Some((line, column)) => self.at(line, column),
+            None => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} {} error", self.day, self.stage)?;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {}, column {}", line, column)?,
+            (Some(line), None) => write!(f, " at line {}", line)?,
+            _ => {}
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Line and column, both 1-based, where `rest` starts inside `input`.
+pub fn position(input: &str, rest: &str) -> Option<(usize, usize)> {
+    let offset = (rest.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    let before = input.get(..offset)?;
+    let line = before.matches('\n').count() + 1;
+    let start = before.rfind('\n').map_or(0, |i| i + 1);
+    Some((line, before[start..].chars().count() + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "abc\ndéf\nghi";
+        assert_eq!(position(input, input), Some((1, 1)));
+        assert_eq!(position(input, &input[7..]), Some((2, 3)));
+        assert_eq!(position(input, &input[9..]), Some((3, 1)));
+        assert_eq!(position(input, "otro"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let error = Error::parse(5, "se esperaba un numero").at(3, 7);
+        assert_eq!(
+            error.to_string(),
+            "day 5 parse error at line 3, column 7: se esperaba un numero"
+        );
+        assert_eq!(
+            Error::solve(10, "no hay inicio").to_string(),
+            "day 10 solve error: no hay inicio"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Parsing blocks of text into grids and the [`Grid`] the grid days work on.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse::trimmed, Error, Point};
+
+/// Parses a rectangular block of text into rows of characters.
+pub fn parse(input: &str) -> Vec<Vec<char>> {
+    parse_with(input, |c| c)
+}
+
+/// Parses a rectangular block of text, mapping every character with `f`. Blank
+/// lines at the end are not rows.
+pub fn parse_with<T>(input: &str, f: impl Fn(char) -> T) -> Vec<Vec<T>> {
+    trimmed(input)
+        .lines()
+        .map(|line| line.chars().map(&f).collect())
+        .collect()
+}
+
+/// Like [`parse`], but the grid can't be empty, every row must be as wide as
+/// the first one and only characters in `allowed` may appear.
+pub fn parse_checked(day: u8, input: &str, allowed: &str) -> Result<Vec<Vec<char>>, Error> {
+    let grid = parse(input);
+    let width = grid.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(Error::parse(day, "la grilla esta vacia"));
+    }
+    for (y, row) in grid.iter().enumerate() {
+        if let Some(x) = row.iter().position(|c| !allowed.contains(*c)) {
+            let message = format!("caracter invalido {:?}", row[x]);
+            return Err(Error::parse(day, message).at(y + 1, x + 1));
+        }
+        if row.len() != width {
+            let message = format!("la fila tiene {} columnas, no {}", row.len(), width);
+            return Err(Error::parse(day, message).at(y + 1, row.len().min(width) + 1));
+        }
+    }
+    Ok(grid)
+}
+
+/// Every character of the input together with its position.
+pub fn cells(input: &str) -> impl Iterator<Item = (Point, char)> + '_ {
+    trimmed(input).lines().enumerate().flat_map(|(y, line)| {
+        line.chars()
+            .enumerate()
+            .map(move |(x, c)| (Point::new(x as i64, y as i64), c))
+    })
+}
+
+/// A rectangular grid stored row by row in one `Vec`, indexed by [`Point`]
+/// with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses the input like [`parse_checked`].
+    pub fn parse(day: u8, input: &str, allowed: &str) -> Result<Grid<char>, Error> {
+        Ok(Grid::from_rows(parse_checked(day, input, allowed)?))
+    }
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be as wide as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "las filas no tienen todas el mismo ancho"
+        );
+        let cells = rows.into_iter().flatten().collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` is inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// The cell at `point`, `None` outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    /// Like [`Grid::get`], for changing the cell.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as i64, (i / width) as i64))
+    }
+
+    /// Every cell together with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, for which `f` is true.
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| f(value))
+            .map(|(point, _)| point)
+    }
+
+    /// The orthogonal neighbours of `point` that are inside the grid.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbors().into_iter().filter(|p| self.contains(*p))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that are inside the
+    /// grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|delta| *delta != Point::default())
+            .map(move |delta| point + delta)
+            .filter(|p| self.contains(*p))
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "la columna {} no esta en la grilla", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every column, left to right.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Turns the grid a quarter to the right, the first column becomes the
+    /// first row read bottom up.
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flat_map(|column| column.rev().cloned())
+                .collect(),
+        }
+    }
+
+    /// Turns the grid a quarter to the left, the last column becomes the first
+    /// row.
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().rev().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} esta fuera de la grilla", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} esta fuera de la grilla", point))
+    }
+}
+
+/// One row per line, without a line ending after the last one.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("#.\n.#"), vec![vec!['#', '.'], vec!['.', '#']]);
+        assert_eq!(
+            parse_with("12\n34", |c| c.to_digit(10).unwrap()),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_parse_checked() {
+        assert_eq!(parse_checked(1, "#.\n.#\n", "#."), Ok(parse("#.\n.#")));
+        assert_eq!(
+            parse_checked(1, "#.\r\n.#\r\n\r\n", "#."),
+            Ok(parse("#.\n.#"))
+        );
+        let error = parse_checked(1, "#.\n.x", "#.").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        let error = parse_checked(1, "#.\n.", "#.").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        assert!(parse_checked(1, "", "#.").is_err());
+    }
+
+    #[test]
+    fn test_cells() {
+        let cells = cells("ab\ncd").collect::<Vec<_>>();
+        assert_eq!(cells[0], (Point::new(0, 0), 'a'));
+        assert_eq!(cells[3], (Point::new(1, 1), 'd'));
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::parse(1, "ab\ncd\nef\n", "abcdef").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.row(1), ['c', 'd']);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.position(|c| *c == 'd'), Some(Point::new(1, 1)));
+        grid[Point::new(0, 0)] = 'x';
+        assert_eq!(grid.to_string(), "xb\ncd\nef");
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        let corner = grid.neighbors8(Point::new(2, 2)).collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            [Point::new(1, 1), Point::new(2, 1), Point::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_grid_turns() {
+        let grid = Grid::parse(1, "abc\ndef", "abcdef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        let back = grid.rotate_clockwise().rotate_counterclockwise();
+        assert_eq!(back, grid);
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()).width(), 3);
+    }
+
+    #[test]
+    fn test_empty_turns() {
+        let size = |grid: Grid<u8>| (grid.width(), grid.height());
+        let thin = Grid::new(0, 3, 0_u8);
+        assert_eq!(size(thin.rotate_clockwise()), (3, 0));
+        assert_eq!(size(thin.rotate_counterclockwise()), (3, 0));
+        assert_eq!(size(thin.transpose()), (3, 0));
+        assert_eq!(thin.rotate_clockwise().rotate_counterclockwise(), thin);
+        let flat = Grid::new(3, 0, 0_u8);
+        assert_eq!(size(flat.rotate_clockwise()), (0, 3));
+        assert_eq!(size(flat.rotate_counterclockwise()), (0, 3));
+    }
+}
@@ -0,0 +1,19 @@
+//! Loading puzzle input from files or standard input.
+
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Reads a whole puzzle input, `-` reads standard input instead of a file.
+pub fn read(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    let mut s = String::new();
+    if path == Path::new("-") {
+        io::stdin().read_to_string(&mut s)?;
+    } else {
+        File::open(path)?.read_to_string(&mut s)?;
+    }
+    Ok(s)
+}
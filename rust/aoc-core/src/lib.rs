@@ -0,0 +1,22 @@
+//! Helpers shared by every day of Advent of Code 2023.
+//!
+//! Points, directions, grid parsing, number theory and input loading used to
+//! be copied from day to day; they live here now so every crate gets the same
+//! fixes.
+
+#![warn(missing_docs)]
+
+pub mod answers;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod solution;
+
+pub use error::{Error, Stage};
+pub use point::{Direction, Point};
+pub use render::Visualize;
+pub use solution::Solution;
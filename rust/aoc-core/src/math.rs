@@ -0,0 +1,51 @@
+//! Number theory for the days that line up cycles.
+
+/// Greatest common divisor, `gcd(a, 0)` is `a`.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        return a;
+    }
+    gcd(b, a % b)
+}
+
+/// Least common multiple, `0` if either number is and `None` if it doesn't
+/// fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of every number, `1` for an empty slice and `None`
+/// if it doesn't fit in a `u64`.
+pub fn lcm_all(nums: &[u64]) -> Option<u64> {
+    nums.iter().try_fold(1, |acc, &num| lcm(acc, num))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+    }
+
+    #[test]
+    fn test_lcm_all() {
+        assert_eq!(lcm_all(&[2, 3, 4]), Some(12));
+        assert_eq!(lcm_all(&[3761, 3767]), Some(3761 * 3767));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[6, 0]), Some(0));
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm_all(&[1 << 40, 1 << 63]), Some(1 << 63));
+        assert_eq!(lcm_all(&[1 << 40, 3, 1 << 63]), None);
+    }
+}
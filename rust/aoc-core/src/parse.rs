@@ -0,0 +1,183 @@
+//! Runs nom parsers over puzzle input and turns their failures into an
+//! [`Error`] pointing at the line and column where they stopped.
+
+use nom::IResult;
+
+use crate::error::Error;
+
+fn nom_error(day: u8, input: &str, error: nom::Err<nom::error::Error<&str>>) -> Error {
+    match error {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            Error::parse(day, format!("no se pudo parsear ({:?})", e.code)).at_rest(input, e.input)
+        }
+        nom::Err::Incomplete(_) => Error::parse(day, "entrada incompleta"),
+    }
+}
+
+fn leftover(day: u8, input: &str, rest: &str) -> Result<(), Error> {
+    if rest.trim().is_empty() {
+        Ok(())
+    } else {
+        Err(Error::parse(day, "texto de mas").at_rest(input, rest.trim_start()))
+    }
+}
+
+/// Runs `parser` over the whole input, only whitespace may be left over.
+pub fn all<'a, T>(
+    day: u8,
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, Error> {
+    within(day, input, input, parser)
+}
+
+/// Runs `parser` over `piece`, a slice of `input`, errors point at where they
+/// are in the whole input.
+pub fn within<'a, T>(
+    day: u8,
+    input: &str,
+    piece: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, Error> {
+    let (rest, value) = parser(piece).map_err(|e| nom_error(day, input, e))?;
+    leftover(day, input, rest)?;
+    Ok(value)
+}
+
+/// The input without the line endings and blank lines at its end, whether it
+/// uses LF or CRLF.
+pub fn trimmed(input: &str) -> &str {
+    input.trim_end_matches(['\r', '\n'])
+}
+
+/// Splits the input at its blank lines, every block comes with the number of
+/// its first line and without its last line ending.
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    let mut end = 0;
+    for (n, line) in input.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.trim().is_empty() {
+            if let Some((first, from)) = start.take() {
+                blocks.push((first, &input[from..end]));
+            }
+        } else {
+            start.get_or_insert((n + 1, offset));
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some((first, from)) = start {
+        blocks.push((first, &input[from..end]));
+    }
+    blocks
+}
+
+/// Runs `parser` over every line of the input.
+pub fn lines<'a, T>(
+    day: u8,
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, Error> {
+    trimmed(input)
+        .lines()
+        .map(|line| within(day, input, line, &mut parser))
+        .collect()
+}
+
+/// Runs `f` over every line of the input, a failure is reported with its line
+/// number. Lines lose any `\r` at their end, even the last one when it has no
+/// `\n`, and blank lines at the end of the input are skipped.
+pub fn lines_with<'a, T>(
+    day: u8,
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, String>,
+) -> Result<Vec<T>, Error> {
+    trimmed(input)
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(n, line)| {
+            let line = line.trim_end_matches(['\r', '\n']);
+            f(line).map_err(|e| Error::parse(day, e).at_line(n + 1))
+        })
+        .collect()
+}
+
+/// Checks a parser against its writer, for the fuzz targets. `rewrite`
+/// parses and writes back what it got, which has to parse and write the
+/// same again. Input it rejects only has to not panic.
+pub fn round_trip(input: &str, rewrite: impl Fn(&str) -> Result<String, Error>) {
+    if let Ok(written) = rewrite(input) {
+        match rewrite(&written) {
+            Ok(again) => assert_eq!(again, written, "lo escrito no se lee igual"),
+            Err(e) => panic!("no se pudo leer lo escrito: {}\n{}", e, written),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{bytes::complete::tag, character::complete::u32, sequence::preceded};
+
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let parser = |line| preceded(tag("n "), u32)(line);
+        assert_eq!(lines(1, "n 1\nn 2\n", parser), Ok(vec![1, 2]));
+        assert_eq!(lines(1, "n 1\r\nn 2\r\n\r\n", parser), Ok(vec![1, 2]));
+
+        let error = lines(1, "n 1\nn x", parser).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        let error = lines(1, "n 1 2", parser).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(5)));
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(all(1, "7\n", u32), Ok(7));
+        let error = all(1, "7\n8", u32).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let rewrite = |input: &str| all(1, input, u32).map(|n| n.to_string());
+        round_trip("007\n", rewrite);
+        round_trip("x", rewrite);
+    }
+
+    #[test]
+    #[should_panic(expected = "no se lee igual")]
+    fn test_round_trip_changed() {
+        round_trip("7", |input| Ok(format!("{}0", input)));
+    }
+
+    #[test]
+    fn test_lines_with() {
+        let error = lines_with(2, "1\nx", |l| l.parse::<u8>().map_err(|e| e.to_string()));
+        assert_eq!(error.unwrap_err().line, Some(2));
+        let lines = lines_with(2, "1\r\n2\r\r\n3\r", Ok::<_, String>);
+        assert_eq!(lines, Ok(vec!["1", "2", "3"]));
+        let lines = lines_with(2, "1\n2\n\n", Ok::<_, String>);
+        assert_eq!(lines, Ok(vec!["1", "2"]));
+    }
+
+    #[test]
+    fn test_trimmed() {
+        assert_eq!(trimmed("a\nb\r\n\r\n"), "a\nb");
+        assert_eq!(trimmed("a\nb"), "a\nb");
+        assert_eq!(trimmed("\n"), "");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\nc\n";
+        assert_eq!(blocks(input), vec![(1, "a\nb"), (4, "c")]);
+        let input = "\r\na\r\nb\r\n\r\n\r\nc\r\n\r\n";
+        assert_eq!(blocks(input), vec![(2, "a\r\nb"), (6, "c")]);
+        assert!(blocks("\n\n").is_empty());
+    }
+}
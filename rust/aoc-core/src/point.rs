@@ -0,0 +1,146 @@
+//! Positions and directions on a 2D grid.
+
+use std::ops::{Add, Sub};
+
+/// The four cardinal directions, with `North` pointing to smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    /// Up, towards smaller `y`.
+    North,
+    /// Down, towards bigger `y`.
+    South,
+    /// Right, towards bigger `x`.
+    East,
+    /// Left, towards smaller `x`.
+    West,
+}
+
+impl Direction {
+    /// Every direction, in the order neighbours are listed.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    /// The direction pointing the other way.
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    /// Offset of one step in this direction.
+    pub fn delta(&self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::South => Point::new(0, 1),
+            Direction::East => Point::new(1, 0),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+/// A position on a 2D grid, `x` grows to the east and `y` to the south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    /// Column, growing to the east.
+    pub x: i64,
+    /// Row, growing to the south.
+    pub y: i64,
+}
+
+impl Point {
+    /// The point at column `x` and row `y`.
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.delta()
+    }
+
+    /// The four orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbors(&self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// Steps between both points moving only along the axes.
+    pub fn manhattan_distance(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let point = Point::new(3, 3);
+        assert_eq!(point.step(Direction::North), Point::new(3, 2));
+        assert_eq!(point.step(Direction::West), Point::new(2, 3));
+        assert_eq!(
+            point.neighbors(),
+            [
+                Point::new(3, 2),
+                Point::new(3, 4),
+                Point::new(4, 3),
+                Point::new(2, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(
+                direction.delta() + direction.opposite().delta(),
+                Point::default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(Point::new(1, 6).manhattan_distance(&Point::new(5, 11)), 9);
+    }
+}
@@ -0,0 +1,296 @@
+//! Frames of the grid days for the terminal, PNG and GIF. The image writers
+//! need the `images` feature.
+
+use std::fmt::Write as _;
+#[cfg(feature = "images")]
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{grid::Grid, Error, Point, Solution};
+
+/// A colour for terminals that understand 24 bit escapes and for images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// A path walked through the grid.
+    pub const PATH: Rgb = Rgb(230, 60, 60);
+    /// A closed loop, like day 10's pipe.
+    pub const LOOP: Rgb = Rgb(60, 140, 230);
+    /// Cells enclosed by a loop.
+    pub const INSIDE: Rgb = Rgb(240, 200, 60);
+    /// Cells a beam went through.
+    pub const ENERGIZED: Rgb = Rgb(250, 150, 30);
+    /// Cells that can be reached.
+    pub const REACHABLE: Rgb = Rgb(70, 190, 90);
+    /// Where a walk starts.
+    pub const START: Rgb = Rgb(200, 80, 220);
+
+    /// Colour of a cell no overlay covers: walls light, floor dark and
+    /// digits from dark to light.
+    fn of(glyph: char) -> Rgb {
+        match glyph {
+            '.' | ' ' => Rgb(25, 25, 25),
+            '#' => Rgb(200, 200, 200),
+            '0'..='9' => {
+                let level = 40 + 20 * (glyph as u8 - b'0');
+                Rgb(level, level, level)
+            }
+            _ => Rgb(110, 110, 110),
+        }
+    }
+}
+
+/// A grid of characters with coloured overlays on top, the unit every
+/// visualization is made of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Shown above the grid.
+    pub title: String,
+    glyphs: Grid<char>,
+    colors: Grid<Option<Rgb>>,
+}
+
+impl Frame {
+    /// A frame showing `grid` with no overlays yet.
+    pub fn new(title: impl Into<String>, grid: &Grid<char>) -> Self {
+        Frame {
+            title: title.into(),
+            glyphs: grid.clone(),
+            colors: grid.map(|_| None),
+        }
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.glyphs.width()
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.glyphs.height()
+    }
+
+    /// Paints `points` with `color`, over any overlay added before.
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, color: Rgb) -> Self {
+        for point in points {
+            self.colors[point] = Some(color);
+        }
+        self
+    }
+
+    /// Like [`Frame::overlay`], also replacing the character of each cell.
+    pub fn glyphs(mut self, cells: impl IntoIterator<Item = (Point, char)>, color: Rgb) -> Self {
+        for (point, glyph) in cells {
+            self.glyphs[point] = glyph;
+            self.colors[point] = Some(color);
+        }
+        self
+    }
+
+    /// Colour of the cell at `point`, its overlay if it has one.
+    pub fn color(&self, point: Point) -> Rgb {
+        self.colors[point].unwrap_or_else(|| Rgb::of(self.glyphs[point]))
+    }
+
+    /// The title and then the grid, overlays as background colours.
+    pub fn terminal(&self) -> String {
+        let mut out = format!("{}\n", self.title);
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let point = Point::new(x as i64, y as i64);
+                match self.colors[point] {
+                    Some(Rgb(r, g, b)) => {
+                        let glyph = self.glyphs[point];
+                        let _ = write!(out, "\x1b[48;2;{};{};{}m{}\x1b[0m", r, g, b, glyph);
+                    }
+                    None => out.push(self.glyphs[point]),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// RGB bytes row by row, each cell a `scale` by `scale` square.
+    #[cfg(feature = "images")]
+    fn pixels(&self, scale: usize) -> Vec<Rgb> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale);
+        for y in 0..self.height() {
+            let row: Vec<Rgb> = (0..self.width())
+                .flat_map(|x| [self.color(Point::new(x as i64, y as i64))].repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend(&row);
+            }
+        }
+        pixels
+    }
+}
+
+/// A [`Solution`] that can show how it gets its answers.
+pub trait Visualize: Solution {
+    /// The frames to show for `input`, in order.
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Error>;
+}
+
+pub(crate) fn frames<V: Visualize>(input: &str) -> Result<Vec<Frame>, Error> {
+    V::frames(&V::parse(input)?)
+}
+
+/// Saves `frame` as a PNG with every cell `scale` pixels wide.
+#[cfg(feature = "images")]
+pub fn write_png(path: impl AsRef<Path>, frame: &Frame, scale: usize) -> io::Result<()> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = frame
+        .pixels(scale)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(io::Error::other)
+}
+
+/// Saves `frames` as a GIF that loops forever, showing each one for `delay`.
+/// Every frame must have the same size.
+#[cfg(feature = "images")]
+pub fn write_gif(
+    path: impl AsRef<Path>,
+    frames: &[Frame],
+    scale: usize,
+    delay: Duration,
+) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let first = frames.first().ok_or_else(|| invalid("no hay cuadros"))?;
+    if frames
+        .iter()
+        .any(|f| (f.width(), f.height()) != (first.width(), first.height()))
+    {
+        return Err(invalid("los cuadros no tienen el mismo tamaño"));
+    }
+    let (width, height) = (first.width() * scale, first.height() * scale);
+    let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => return Err(invalid("la imagen es demasiado grande para un GIF")),
+    };
+
+    // one palette for the whole animation if the colours fit in it, else
+    // the encoder picks one for each frame
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+    let mut colors = Vec::new();
+    for color in frames
+        .iter()
+        .flat_map(|f| f.glyphs.points().map(|p| f.color(p)))
+    {
+        if !palette.contains_key(&color) {
+            let Ok(index) = u8::try_from(colors.len()) else {
+                palette.clear();
+                colors.clear();
+                break;
+            };
+            palette.insert(color, index);
+            colors.push(color);
+        }
+    }
+    let colors: Vec<u8> = colors.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &colors).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for pixels in frames.iter().map(|f| f.pixels(scale)) {
+        let mut frame = if palette.is_empty() {
+            let rgb: Vec<u8> = pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+            gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+        } else {
+            let buffer = pixels.iter().map(|color| palette[color]).collect();
+            gif::Frame {
+                width,
+                height,
+                buffer,
+                ..gif::Frame::default()
+            }
+        };
+        frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse(1, "#.\n.#", "#.").unwrap();
+        Frame::new("prueba", &grid)
+            .overlay([Point::new(1, 0)], Rgb::PATH)
+            .glyphs([(Point::new(0, 1), '>')], Rgb::LOOP)
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = frame();
+        assert_eq!(frame.color(Point::new(0, 0)), Rgb::of('#'));
+        assert_eq!(frame.color(Point::new(1, 0)), Rgb::PATH);
+        assert_eq!(
+            frame.terminal(),
+            "prueba\n#\x1b[48;2;230;60;60m.\x1b[0m\n\x1b[48;2;60;140;230m>\x1b[0m#\n"
+        );
+    }
+
+    #[cfg(feature = "images")]
+    #[test]
+    fn test_write_images() {
+        use std::{env, fs};
+
+        assert_eq!(frame().pixels(3).len(), 36);
+        let dir = env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        write_png(dir.join("frame.png"), &frame(), 4).unwrap();
+        let decoder = png::Decoder::new(File::open(dir.join("frame.png")).unwrap());
+        let info = decoder.read_info().unwrap();
+        assert_eq!((info.info().width, info.info().height), (8, 8));
+
+        let frames = [frame(), frame().overlay([Point::new(1, 1)], Rgb::START)];
+        write_gif(
+            dir.join("frames.gif"),
+            &frames,
+            2,
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(dir.join("frames.gif")).unwrap())
+            .unwrap();
+        let mut count = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height), (4, 4));
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        assert!(write_gif(dir.join("empty.gif"), &[], 2, Duration::ZERO).is_err());
+
+        let grid = Grid::new(30, 10, '.');
+        let colors = grid.points().map(|p| (p, Rgb(p.x as u8, p.y as u8, 0)));
+        let many = colors.fold(Frame::new("colores", &grid), |frame, (point, color)| {
+            frame.overlay([point], color)
+        });
+        write_gif(dir.join("many.gif"), &[many], 1, Duration::ZERO).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+//! The [`Solution`] every day implements and running it with timings.
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Error,
+    parse,
+    render::{self, Frame, Visualize},
+};
+
+/// One day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    /// Day of the puzzle, 1 to 25.
+    const DAY: u8;
+    /// Parsed puzzle input, it may borrow from the text it was parsed from.
+    type Input<'a>;
+    /// Answer of part 1.
+    type Part1: Display;
+    /// Answer of part 2, [`NoAnswer`] if the puzzle has a single part.
+    type Part2: Display;
+
+    /// Turns the puzzle text into [`Solution::Input`], both parts share it.
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error>;
+    /// Solves part 1 from the parsed input.
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, Error>;
+    /// Solves part 2 from the parsed input.
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, Error>;
+}
+
+/// Answer type for a part the puzzle doesn't have, it can't be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoAnswer {}
+
+impl Display for NoAnswer {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+/// What a stage produced and how long it took.
+#[derive(Debug, Clone)]
+pub struct Timed<T> {
+    /// What the stage gave.
+    pub result: Result<T, Error>,
+    /// How long it ran.
+    pub time: Duration,
+}
+
+impl<T> Timed<T> {
+    fn measure(f: impl FnOnce() -> Result<T, Error>) -> Self {
+        let now = Instant::now();
+        let result = f();
+        Timed {
+            result,
+            time: now.elapsed(),
+        }
+    }
+}
+
+/// The answer of one part, as text.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    /// Part of the puzzle, 1 or 2.
+    pub part: u8,
+    /// The answer and how long it took.
+    pub answer: Timed<String>,
+}
+
+/// Result of parsing once and running some parts over the parsed input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// Day of the puzzle.
+    pub day: u8,
+    /// Parsing, without the parsed input.
+    pub parse: Timed<()>,
+    /// The parts that ran, empty if parsing failed.
+    pub parts: Vec<PartRun>,
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Run {
+    let mut parsed = None;
+    let parse = Timed::measure(|| S::parse(input).map(|p| parsed = Some(p)));
+    let parts = match parsed {
+        Some(parsed) => parts
+            .iter()
+            .filter_map(|&part| {
+                let answer = match part {
+                    1 => Timed::measure(|| S::part1(&parsed).map(|a| a.to_string())),
+                    2 => Timed::measure(|| S::part2(&parsed).map(|a| a.to_string())),
+                    _ => return None,
+                };
+                Some(PartRun { part, answer })
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    Run {
+        day: S::DAY,
+        parse,
+        parts,
+    }
+}
+
+type Frames = fn(&str) -> Result<Vec<Frame>, Error>;
+
+/// A [`Solution`] with its types erased, so tools can keep every day in one
+/// list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    /// Day of the puzzle.
+    pub day: u8,
+    /// Puzzle input used when no other is given.
+    pub input: &'static str,
+    /// Known answers for that input, see [`Answers`](crate::answers::Answers).
+    pub answers: &'static str,
+    /// How many parts the puzzle has.
+    pub parts: u8,
+    run: fn(&str, &[u8]) -> Run,
+    frames: Option<Frames>,
+}
+
+impl Day {
+    /// The day solved by `S`, with two parts and no visualizer.
+    pub const fn new<S: Solution>(input: &'static str, answers: &'static str) -> Self {
+        Day {
+            day: S::DAY,
+            input,
+            answers,
+            parts: 2,
+            run: run::<S>,
+            frames: None,
+        }
+    }
+
+    /// Sets how many parts the puzzle has.
+    pub const fn with_parts(self, parts: u8) -> Self {
+        Day { parts, ..self }
+    }
+
+    /// Registers the day's [`Visualize`] impl, `V` should be the same type as
+    /// the solution.
+    pub const fn with_visualizer<V: Visualize>(self) -> Self {
+        Day {
+            frames: Some(render::frames::<V>),
+            ..self
+        }
+    }
+
+    /// Parses `input` and runs the requested parts, skipping the ones the
+    /// puzzle doesn't have.
+    pub fn run(&self, input: &str, parts: &[u8]) -> Run {
+        let parts: Vec<u8> = parts
+            .iter()
+            .copied()
+            .filter(|part| (1..=self.parts).contains(part))
+            .collect();
+        (self.run)(input, &parts)
+    }
+
+    /// Frames showing how the day solves `input`, `None` if it has no
+    /// visualizer.
+    pub fn frames(&self, input: &str) -> Option<Result<Vec<Frame>, Error>> {
+        self.frames.map(|frames| frames(input))
+    }
+}
+
+/// Checks that `S` gives the same answers for `input` with LF or CRLF line
+/// endings and with or without a blank line at the end, for the tests of every
+/// day.
+pub fn check_line_endings<S: Solution>(input: &str) {
+    let lf = input.replace("\r\n", "\n");
+    let lf = parse::trimmed(&lf);
+    let crlf = lf.replace('\n', "\r\n");
+    let variants = [
+        ("LF", lf.to_owned()),
+        ("LF y salto final", format!("{}\n", lf)),
+        ("LF y linea en blanco", format!("{}\n\n", lf)),
+        ("CRLF", crlf.clone()),
+        ("CRLF y salto final", format!("{}\r\n", crlf)),
+        ("CRLF y linea en blanco", format!("{}\r\n\r\n", crlf)),
+    ];
+    let day = Day::new::<S>("", "");
+    let answers = |(name, input): &(&str, String)| {
+        let run = day.run(input, &[1, 2]);
+        if let Err(e) = run.parse.result {
+            panic!("no se pudo leer la entrada con {}: {}", name, e);
+        }
+        let answers = run.parts.into_iter().map(|p| p.answer.result.ok());
+        answers.collect::<Vec<_>>()
+    };
+    let expected = answers(&variants[0]);
+    for variant in &variants[1..] {
+        assert_eq!(
+            answers(variant),
+            expected,
+            "con {} cambian las respuestas",
+            variant.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = u32;
+        type Part2 = NoAnswer;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+            Ok(parse::trimmed(input).lines().collect())
+        }
+        fn part1(input: &Self::Input<'_>) -> Result<u32, Error> {
+            input
+                .iter()
+                .map(|line| {
+                    line.parse::<u32>()
+                        .map_err(|e| Error::solve(1, e.to_string()))
+                })
+                .sum()
+        }
+        fn part2(_: &Self::Input<'_>) -> Result<NoAnswer, Error> {
+            Err(Error::solve(1, "no part 2"))
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let day = Day::new::<Sum>("", "").with_parts(1);
+        let run = day.run("1\n2\n3", &[1, 2]);
+        assert_eq!(run.day, 1);
+        assert!(run.parse.result.is_ok());
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].answer.result, Ok("6".to_owned()));
+    }
+
+    #[test]
+    fn test_run_error() {
+        let run = Day::new::<Sum>("", "").run("1\nx", &[1]);
+        let error = run.parts[0].answer.result.clone().unwrap_err();
+        assert_eq!(error.stage, crate::Stage::Solve);
+    }
+
+    #[test]
+    fn test_check_line_endings() {
+        check_line_endings::<Sum>("1\n2\n3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "con LF y salto final cambian las respuestas")]
+    fn test_check_line_endings_changed() {
+        struct Lines;
+        impl Solution for Lines {
+            const DAY: u8 = 1;
+            type Input<'a> = usize;
+            type Part1 = usize;
+            type Part2 = NoAnswer;
+
+            fn parse(input: &str) -> Result<usize, Error> {
+                Ok(input.split('\n').count())
+            }
+            fn part1(lines: &usize) -> Result<usize, Error> {
+                Ok(*lines)
+            }
+            fn part2(_: &usize) -> Result<NoAnswer, Error> {
+                Err(Error::solve(1, "no part 2"))
+            }
+        }
+        check_line_endings::<Lines>("1\n2");
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Random spanning tree over a `width` x `height` grid of cells, as the
+/// edges between neighbouring cells.
+pub(crate) fn spanning_tree(
+    rng: &mut impl Rng,
+    width: usize,
+    height: usize,
+) -> Vec<((usize, usize), (usize, usize))> {
+    let mut visited = HashSet::from([(0, 0)]);
+    let mut frontier = vec![(0, 0)];
+    let mut edges = Vec::new();
+    while !frontier.is_empty() {
+        // picking any cell of the frontier gives branchy trees, the last one
+        // long corridors
+        let index = if rng.gen_bool(0.5) {
+            frontier.len() - 1
+        } else {
+            rng.gen_range(0..frontier.len())
+        };
+        let (x, y): (usize, usize) = frontier[index];
+        let mut next = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < width && ny < height && !visited.contains(&(nx, ny))).then_some((nx, ny))
+            })
+            .collect::<Vec<_>>();
+        next.shuffle(rng);
+        match next.first() {
+            Some(&cell) => {
+                visited.insert(cell);
+                frontier.push(cell);
+                edges.push(((x, y), cell));
+            }
+            None => {
+                frontier.swap_remove(index);
+            }
+        }
+    }
+    edges
+}
+
+/// Simple closed loop of unit steps around a random tree of `width` x
+/// `height` cells, its corners fit in a `2 * width` x `2 * height` grid.
+pub(crate) fn rectilinear_loop(rng: &mut impl Rng, width: usize, height: usize) -> Vec<(i64, i64)> {
+    // the tree drawn at twice the resolution, cells on even pixels and the
+    // edges between them, has no pixels touching only by a corner
+    let mut pixels = (0..width)
+        .flat_map(|x| (0..height).map(move |y| (2 * x as i64, 2 * y as i64)))
+        .collect::<HashSet<_>>();
+    for ((ax, ay), (bx, by)) in spanning_tree(rng, width, height) {
+        pixels.insert(((ax + bx) as i64, (ay + by) as i64));
+    }
+    let mut corners: HashMap<(i64, i64), Vec<(i64, i64)>> = HashMap::new();
+    for &(x, y) in &pixels {
+        let sides = [
+            ((0, -1), (x, y), (x + 1, y)),
+            ((0, 1), (x, y + 1), (x + 1, y + 1)),
+            ((-1, 0), (x, y), (x, y + 1)),
+            ((1, 0), (x + 1, y), (x + 1, y + 1)),
+        ];
+        for ((dx, dy), a, b) in sides {
+            if !pixels.contains(&(x + dx, y + dy)) {
+                corners.entry(a).or_default().push(b);
+                corners.entry(b).or_default().push(a);
+            }
+        }
+    }
+    let start = *corners
+        .keys()
+        .min()
+        .expect("el arbol tiene al menos una celda");
+    let mut path = vec![start];
+    let mut previous = start;
+    let mut current = corners[&start][0];
+    while current != start {
+        path.push(current);
+        let next = corners[&current]
+            .iter()
+            .copied()
+            .find(|&corner| corner != previous)
+            .expect("cada esquina del borde tiene dos vecinos");
+        previous = current;
+        current = next;
+    }
+    path
+}
+
+/// Points of a loop where it turns, in the same order.
+pub(crate) fn corners(path: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let len = path.len();
+    (0..len)
+        .filter(|&i| {
+            let (px, py) = path[(i + len - 1) % len];
+            let (x, y) = path[i];
+            let (nx, ny) = path[(i + 1) % len];
+            (x - px, y - py) != (nx - x, ny - y)
+        })
+        .map(|i| path[i])
+        .collect()
+}
+
+/// Random lowercase name of `len` letters not in `taken`, which keeps it.
+pub(crate) fn unique_name(rng: &mut impl Rng, len: usize, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name = (0..len)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Cells of a grid joined into lines, with a trailing newline.
+pub(crate) fn lines(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rectilinear_loop() {
+        let rng = &mut crate::rng(3);
+        let path = rectilinear_loop(rng, 6, 4);
+        let distinct = path.iter().collect::<HashSet<_>>();
+        assert_eq!(distinct.len(), path.len());
+        for (i, &(x, y)) in path.iter().enumerate() {
+            let (nx, ny) = path[(i + 1) % path.len()];
+            assert_eq!((nx - x).abs() + (ny - y).abs(), 1);
+            assert!((0..=12).contains(&x) && (0..=8).contains(&y));
+        }
+        let corners = corners(&path);
+        assert_eq!(corners.len() % 2, 0);
+        for (i, &(x, y)) in corners.iter().enumerate() {
+            let (nx, ny) = corners[(i + 1) % corners.len()];
+            assert!(x == nx || y == ny);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Calibration lines, letters with digits and spelled out digits among them.
+
+use rand::Rng;
+
+pub struct Config {
+    pub lines: usize,
+    pub max_len: usize,
+    /// Chance of writing a digit, spelled or not, instead of a letter.
+    pub digits: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            lines: 1000,
+            max_len: 50,
+            digits: 0.15,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(lines: usize) -> Self {
+        Config {
+            lines,
+            ..Default::default()
+        }
+    }
+}
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut input = String::new();
+    for _ in 0..config.lines {
+        let len = rng.gen_range(1..=config.max_len.max(1));
+        let mut line = String::new();
+        // part 1 needs at least one digit in every line
+        let digit = rng.gen_range(0..len);
+        for i in 0..len {
+            if i == digit {
+                line.push(rng.gen_range('1'..='9'));
+            } else if rng.gen_bool(config.digits) {
+                if rng.gen_bool(0.5) {
+                    line.push(rng.gen_range('1'..='9'));
+                } else {
+                    line.push_str(WORDS[rng.gen_range(0..WORDS.len())]);
+                }
+            } else {
+                line.push(rng.gen_range('a'..='z'));
+            }
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_01::Day01;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(1), &Config::sized(50));
+        assert_eq!(input.lines().count(), 50);
+        let parsed = Day01::parse(&input).unwrap();
+        assert!(Day01::part1(&parsed).is_ok());
+        assert!(Day01::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,63 @@
+//! Games of cubes drawn from the bag, `Game 1: 3 blue, 4 red; 1 red`.
+
+use rand::{seq::SliceRandom, Rng};
+
+pub struct Config {
+    pub games: usize,
+    pub max_sets: usize,
+    pub max_cubes: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            games: 100,
+            max_sets: 6,
+            max_cubes: 20,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(games: usize) -> Self {
+        Config {
+            games,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut input = String::new();
+    for id in 1..=config.games {
+        let sets = (0..rng.gen_range(1..=config.max_sets.max(1)))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let count = rng.gen_range(1..=colors.len());
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.gen_range(1..=config.max_cubes), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        input.push_str(&format!("Game {}: {}\n", id, sets.join("; ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_02::Day02;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(2), &Config::sized(30));
+        let parsed = Day02::parse(&input).unwrap();
+        assert!(Day02::part1(&parsed).is_ok());
+        assert!(Day02::part2(&parsed).is_ok());
+    }
+}
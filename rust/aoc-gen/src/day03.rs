@@ -0,0 +1,82 @@
+//! Engine schematic, part numbers and symbols on a square grid of dots.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the schematic.
+    pub size: usize,
+    /// Chance of a number starting on a free cell.
+    pub numbers: f64,
+    /// Chance of a symbol on a free cell.
+    pub symbols: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 140,
+            numbers: 0.08,
+            symbols: 0.05,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size;
+    let mut grid = vec![vec!['.'; size]; size];
+    for row in grid.iter_mut() {
+        let mut x = 0;
+        while x < size {
+            if rng.gen_bool(config.numbers) {
+                let number = rng.gen_range(1..1000u32).to_string();
+                // numbers never touch each other on the same row
+                if x + number.len() <= size {
+                    for (i, c) in number.chars().enumerate() {
+                        row[x + i] = c;
+                    }
+                    x += number.len();
+                }
+            }
+            x += 1;
+        }
+    }
+    // like in the real inputs symbols are never on the edge, the solver
+    // looks around them without bounds checks
+    for row in grid.iter_mut().take(size.saturating_sub(1)).skip(1) {
+        for cell in row.iter_mut().take(size.saturating_sub(1)).skip(1) {
+            if *cell == '.' && rng.gen_bool(config.symbols) {
+                *cell = SYMBOLS[rng.gen_range(0..SYMBOLS.len())];
+            }
+        }
+    }
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_03::Day03;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(3), &Config::sized(30));
+        assert_eq!(input.lines().count(), 30);
+        let parsed = Day03::parse(&input).unwrap();
+        assert!(Day03::part1(&parsed).is_ok());
+        assert!(Day03::part2(&parsed).is_ok());
+    }
+}
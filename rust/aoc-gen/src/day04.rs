@@ -0,0 +1,86 @@
+//! Scratchcards, `Card   1: 41 48 83 | 83 86  6 31`.
+
+use rand::{seq::index, Rng};
+
+pub struct Config {
+    pub cards: usize,
+    pub winning: usize,
+    pub numbers: usize,
+    /// Chance of each winning number being among the card's numbers. The
+    /// number of copies grows exponentially unless a card wins fewer than
+    /// one card on average.
+    pub matches: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cards: 200,
+            winning: 10,
+            numbers: 25,
+            matches: 0.08,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(cards: usize) -> Self {
+        Config {
+            cards,
+            ..Default::default()
+        }
+    }
+}
+
+fn numbers(numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{:>2}", n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let width = config.cards.to_string().len();
+    let mut input = String::new();
+    for id in 1..=config.cards {
+        // distinct numbers from 1 to 99, the first ones are the winning ones
+        let pool = index::sample(rng, 99, config.winning + config.numbers)
+            .into_iter()
+            .map(|n| n as u32 + 1)
+            .collect::<Vec<_>>();
+        let (winning, rest) = pool.split_at(config.winning);
+        let mut have = rest.to_vec();
+        // a card can't win copies of cards past the end of the table
+        let mut wins = (config.cards - id).min(config.winning);
+        for (i, &number) in winning.iter().enumerate() {
+            if wins > 0 && i < have.len() && rng.gen_bool(config.matches) {
+                have[i] = number;
+                wins -= 1;
+            }
+        }
+        input.push_str(&format!(
+            "Card {:>width$}: {} | {}\n",
+            id,
+            numbers(winning),
+            numbers(&have),
+            width = width
+        ));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_04::Day04;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(4), &Config::sized(40));
+        let parsed = Day04::parse(&input).unwrap();
+        assert!(Day04::part1(&parsed).is_ok());
+        assert!(Day04::part2(&parsed).unwrap() >= 40);
+    }
+}
@@ -0,0 +1,102 @@
+//! Almanac, seeds followed by the seven maps from seed to location. Every
+//! map is a permutation of `0..max` cut in segments, like the real ones.
+
+use rand::{seq::SliceRandom, Rng};
+
+pub struct Config {
+    /// Seed ranges, part 1 reads them as twice as many seeds.
+    pub seeds: usize,
+    /// Segments of each map.
+    pub segments: usize,
+    pub max: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            seeds: 10,
+            segments: 40,
+            max: 1 << 32,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(segments: usize) -> Self {
+        Config {
+            segments,
+            ..Default::default()
+        }
+    }
+}
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+fn map(rng: &mut impl Rng, config: &Config) -> Vec<(u64, u64, u64)> {
+    let segments = (config.segments as u64).clamp(1, config.max);
+    let mut cuts = rand::seq::index::sample(rng, config.max as usize - 1, segments as usize - 1)
+        .into_iter()
+        .map(|cut| cut as u64 + 1)
+        .collect::<Vec<_>>();
+    cuts.push(0);
+    cuts.push(config.max);
+    cuts.sort_unstable();
+    let mut sources = cuts
+        .windows(2)
+        .map(|w| (w[0], w[1] - w[0]))
+        .collect::<Vec<_>>();
+    sources.shuffle(rng);
+    let mut destination = 0;
+    let mut lines = sources
+        .into_iter()
+        .map(|(source, len)| {
+            destination += len;
+            (destination - len, source, len)
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+    lines
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let seeds = (0..config.seeds)
+        .flat_map(|_| {
+            let start = rng.gen_range(0..config.max);
+            let len = rng.gen_range(1..=(config.max - start).min(config.max / 20).max(1));
+            [start, len]
+        })
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for pair in CATEGORIES.windows(2) {
+        input.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+        for (destination, source, len) in map(rng, config) {
+            input.push_str(&format!("{} {} {}\n", destination, source, len));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_05::Day05;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(5), &Config::sized(10));
+        let parsed = Day05::parse(&input).unwrap();
+        assert!(Day05::part1(&parsed).is_ok());
+        assert!(Day05::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,63 @@
+//! Boat races, a row of times and a row of record distances.
+
+use rand::Rng;
+
+pub struct Config {
+    /// Part 2 tries every speed for the races' digits joined together, so
+    /// more than four two digit races get slow.
+    pub races: usize,
+    pub max_time: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            races: 4,
+            max_time: 99,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(races: usize) -> Self {
+        Config {
+            races,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let races = (0..config.races)
+        .map(|_| {
+            let time = rng.gen_range(2..=config.max_time.max(2));
+            // the record can always be beaten
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.gen_range(0..best))
+        })
+        .collect::<Vec<_>>();
+    let mut times = String::from("Time:    ");
+    let mut distances = String::from("Distance:");
+    for (time, distance) in races {
+        let (time, distance) = (time.to_string(), distance.to_string());
+        let width = time.len().max(distance.len()) + 3;
+        times.push_str(&format!("{:>width$}", time, width = width));
+        distances.push_str(&format!("{:>width$}", distance, width = width));
+    }
+    format!("{}\n{}\n", times, distances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_06::Day06;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(6), &Config::sized(3));
+        let parsed = Day06::parse(&input).unwrap();
+        assert!(Day06::part1(&parsed).unwrap() > 0);
+        assert!(Day06::part2(&parsed).is_ok());
+    }
+}
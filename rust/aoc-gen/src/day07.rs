@@ -0,0 +1,62 @@
+//! Camel Cards hands with their bids, `32T3K 765`.
+
+use rand::Rng;
+
+pub struct Config {
+    pub hands: usize,
+    pub max_bid: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            hands: 1000,
+            max_bid: 1000,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(hands: usize) -> Self {
+        Config {
+            hands,
+            ..Default::default()
+        }
+    }
+}
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut input = String::new();
+    for _ in 0..config.hands {
+        // a few cards of the hand repeat the others to get pairs and more
+        let mut hand = Vec::new();
+        for _ in 0..5 {
+            let card = if !hand.is_empty() && rng.gen_bool(0.4) {
+                hand[rng.gen_range(0..hand.len())]
+            } else {
+                CARDS[rng.gen_range(0..CARDS.len())] as char
+            };
+            hand.push(card);
+        }
+        let hand = hand.into_iter().collect::<String>();
+        input.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=config.max_bid)));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_07::Day07;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(7), &Config::sized(100));
+        let parsed = Day07::parse(&input).unwrap();
+        assert!(Day07::part1(&parsed).is_ok());
+        assert!(Day07::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,121 @@
+//! Desert map, left/right instructions and the network of nodes.
+//!
+//! Every ghost walks from its `..A` node along a path that reaches its `..Z`
+//! node after a prime number of rounds of the instructions and then loops
+//! back, the structure the LCM in part 2 relies on. The first ghost goes
+//! from `AAA` to `ZZZ`.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+pub struct Config {
+    pub instructions: usize,
+    /// Ghosts walking at the same time, at most 12. There are only so many
+    /// three letter names, with many more instructions there are fewer.
+    pub ghosts: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            instructions: 60,
+            ghosts: 6,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(ghosts: usize) -> Self {
+        Config {
+            ghosts,
+            ..Default::default()
+        }
+    }
+}
+
+const PRIMES: [usize; 12] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+// part 1 only reads letters
+const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn name(rng: &mut impl Rng, last: char, taken: &mut HashSet<String>) -> String {
+    loop {
+        let mut name = (0..2)
+            .map(|_| CHARS[rng.gen_range(0..CHARS.len())] as char)
+            .collect::<String>();
+        name.push(last);
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A character for the end of a node's name that doesn't make it a start
+/// or an end.
+fn middle(rng: &mut impl Rng) -> char {
+    loop {
+        let c = CHARS[rng.gen_range(0..CHARS.len())] as char;
+        if c != 'A' && c != 'Z' {
+            return c;
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let instructions = (0..config.instructions.max(1))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect::<Vec<_>>();
+    let mut taken = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+    let mut lines = Vec::new();
+    for (ghost, rounds) in PRIMES.iter().take(config.ghosts).enumerate() {
+        let steps = instructions.len() * rounds;
+        let mut path = Vec::with_capacity(steps + 1);
+        path.push(if ghost == 0 {
+            "AAA".to_owned()
+        } else {
+            name(rng, 'A', &mut taken)
+        });
+        for _ in 1..steps {
+            let last = middle(rng);
+            path.push(name(rng, last, &mut taken));
+        }
+        path.push(if ghost == 0 {
+            "ZZZ".to_owned()
+        } else {
+            name(rng, 'Z', &mut taken)
+        });
+        for (i, node) in path.iter().enumerate() {
+            // the end node follows the first instruction again, back to the
+            // node after the start
+            let next = if i == steps { &path[1] } else { &path[i + 1] };
+            let other = &path[rng.gen_range(1..=steps)];
+            let (left, right) = match instructions[i % instructions.len()] {
+                'L' => (next, other),
+                _ => (other, next),
+            };
+            lines.push(format!("{} = ({}, {})", node, left, right));
+        }
+    }
+    lines.shuffle(rng);
+    let instructions = instructions.into_iter().collect::<String>();
+    format!("{}\n\n{}\n", instructions, lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_08::Day08;
+
+    #[test]
+    fn test_generate() {
+        let config = Config {
+            instructions: 7,
+            ghosts: 3,
+        };
+        let input = generate(&mut crate::rng(8), &config);
+        let parsed = Day08::parse(&input).unwrap();
+        assert_eq!(Day08::part1(&parsed).unwrap(), 7 * 3);
+        assert_eq!(Day08::part2(&parsed).unwrap(), 7 * 3 * 5 * 7);
+    }
+}
@@ -0,0 +1,66 @@
+//! OASIS report, one history of values per line. Every history comes from
+//! a polynomial, so its differences reach zero.
+
+use rand::Rng;
+
+pub struct Config {
+    pub lines: usize,
+    pub values: usize,
+    pub max_degree: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            lines: 200,
+            values: 21,
+            max_degree: 6,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(lines: usize) -> Self {
+        Config {
+            lines,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut input = String::new();
+    for _ in 0..config.lines {
+        let degree = rng.gen_range(0..=config.max_degree);
+        let coefficients = (0..=degree)
+            .map(|_| rng.gen_range(-10..=10i64))
+            .collect::<Vec<_>>();
+        let values = (0..config.values as i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, coefficient| acc * x + coefficient)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_09::Day09;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(9), &Config::sized(20));
+        let parsed = Day09::parse(&input).unwrap();
+        assert!(Day09::part1(&parsed).is_ok());
+        assert!(Day09::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,125 @@
+//! Field of pipes with one big loop through `S`, the rest is junk.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the field.
+    pub size: usize,
+    /// Chance of a pipe on a tile that isn't part of the loop.
+    pub junk: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 140,
+            junk: 0.6,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+const PIPES: &[char] = &['|', '-', 'L', 'J', '7', 'F'];
+
+fn pipe(a: (i64, i64), b: (i64, i64)) -> char {
+    let mut dirs = [a, b];
+    dirs.sort_unstable();
+    match dirs {
+        [(0, -1), (0, 1)] => '|',
+        [(-1, 0), (1, 0)] => '-',
+        [(0, -1), (1, 0)] => 'L',
+        [(-1, 0), (0, -1)] => 'J',
+        [(-1, 0), (0, 1)] => '7',
+        [(0, 1), (1, 0)] => 'F',
+        _ => unreachable!("los pasos del lazo son unitarios"),
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size.max(4);
+    let mut grid = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.gen_bool(config.junk) {
+                        PIPES[rng.gen_range(0..PIPES.len())]
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // the loop's corners fit in twice the tree, one tile away from the edge
+    let cells = (size - 2) / 2;
+    let path = common::rectilinear_loop(rng, cells, cells)
+        .into_iter()
+        .map(|(x, y)| (x + 1, y + 1))
+        .collect::<Vec<_>>();
+    for (i, &(x, y)) in path.iter().enumerate() {
+        let (px, py) = path[(i + path.len() - 1) % path.len()];
+        let (nx, ny) = path[(i + 1) % path.len()];
+        grid[y as usize][x as usize] = pipe((px - x, py - y), (nx - x, ny - y));
+    }
+    // the start only touches the two pipes it connects, the solver takes
+    // any pipe next to it as the way on
+    let on_loop = path.iter().collect::<HashSet<_>>();
+    let touching = |i: usize| {
+        let (x, y) = path[i];
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .filter(|(dx, dy)| on_loop.contains(&(x + dx, y + dy)))
+            .count()
+    };
+    let starts = (0..path.len())
+        .filter(|&i| touching(i) == 2)
+        .collect::<Vec<_>>();
+    let start = starts
+        .choose(rng)
+        .copied()
+        .unwrap_or_else(|| rng.gen_range(0..path.len()));
+    let (sx, sy) = path[start];
+    grid[sy as usize][sx as usize] = 'S';
+    for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+        let (x, y) = ((sx + dx) as usize, (sy + dy) as usize);
+        if !on_loop.contains(&(sx + dx, sy + dy)) {
+            grid[y][x] = '.';
+        }
+    }
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_10::Day10;
+
+    #[test]
+    fn test_generate() {
+        // without junk every pipe is part of the loop
+        let config = Config {
+            size: 20,
+            junk: 0.0,
+        };
+        let input = generate(&mut crate::rng(10), &config);
+        let pipes = input.chars().filter(|c| !".\n".contains(*c)).count();
+        let parsed = Day10::parse(&input).unwrap();
+        assert_eq!(Day10::part1(&parsed).unwrap() as usize, pipes / 2);
+        let input = generate(&mut crate::rng(10), &Config::sized(20));
+        let parsed = Day10::parse(&input).unwrap();
+        assert!(Day10::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,71 @@
+//! Image of galaxies, `#` on a square grid of dots with some empty rows and
+//! columns to expand.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the image.
+    pub size: usize,
+    pub galaxies: f64,
+    /// Chance of a row or column having no galaxies at all.
+    pub empty: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 140,
+            galaxies: 0.025,
+            empty: 0.08,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size;
+    let empty_rows = (0..size)
+        .map(|_| rng.gen_bool(config.empty))
+        .collect::<Vec<_>>();
+    let empty_columns = (0..size)
+        .map(|_| rng.gen_bool(config.empty))
+        .collect::<Vec<_>>();
+    let grid = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(config.galaxies) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_11::Day11;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(11), &Config::sized(30));
+        let parsed = Day11::parse(&input).unwrap();
+        assert!(Day11::part1(&parsed).unwrap() <= Day11::part2(&parsed).unwrap());
+    }
+}
@@ -0,0 +1,78 @@
+//! Condition records, `?###???????? 3,2,1`. Every record has at least one
+//! arrangement, the one it was made from before hiding some springs.
+
+use rand::Rng;
+
+pub struct Config {
+    pub records: usize,
+    pub max_len: usize,
+    pub max_group: usize,
+    /// Chance of a spring being unknown.
+    pub unknown: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            records: 1000,
+            max_len: 20,
+            max_group: 6,
+            unknown: 0.5,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(records: usize) -> Self {
+        Config {
+            records,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let max_len = config.max_len.max(1);
+    let mut input = String::new();
+    for _ in 0..config.records {
+        let len = rng.gen_range(1..=max_len);
+        let mut springs = String::new();
+        let mut groups = Vec::new();
+        while springs.len() < len {
+            let gap = if springs.is_empty() { 0 } else { 1 } + rng.gen_range(0..3);
+            let group = rng.gen_range(1..=config.max_group.max(1));
+            if springs.len() + gap + group > len {
+                break;
+            }
+            springs.push_str(&".".repeat(gap));
+            springs.push_str(&"#".repeat(group));
+            groups.push(group.to_string());
+        }
+        if groups.is_empty() {
+            springs = "#".to_owned();
+            groups.push("1".to_owned());
+        }
+        springs.push_str(&".".repeat(len.saturating_sub(springs.len())));
+        let springs = springs
+            .chars()
+            .map(|c| if rng.gen_bool(config.unknown) { '?' } else { c })
+            .collect::<String>();
+        input.push_str(&format!("{} {}\n", springs, groups.join(",")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_12::Day12;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(12), &Config::sized(50));
+        let parsed = Day12::parse(&input).unwrap();
+        assert!(Day12::part1(&parsed).unwrap() >= 50);
+        assert!(Day12::part2(&parsed).unwrap() >= 50);
+    }
+}
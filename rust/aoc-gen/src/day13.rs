@@ -0,0 +1,123 @@
+//! Patterns of ash and rocks separated by blank lines. Every pattern has one
+//! perfect reflection and one line that needs a smudge fixed, no more.
+
+use rand::Rng;
+
+pub struct Config {
+    pub patterns: usize,
+    pub min_size: usize,
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            patterns: 100,
+            min_size: 5,
+            max_size: 17,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(patterns: usize) -> Self {
+        Config {
+            patterns,
+            ..Default::default()
+        }
+    }
+}
+
+/// How many cells differ across each vertical line, then each horizontal.
+fn mismatches(grid: &[Vec<char>]) -> Vec<usize> {
+    let (height, width) = (grid.len(), grid[0].len());
+    let vertical = (1..width).map(|v| {
+        grid.iter()
+            .map(|row| {
+                (0..v.min(width - v))
+                    .filter(|x| row[v - 1 - x] != row[v + x])
+                    .count()
+            })
+            .sum()
+    });
+    let horizontal = (1..height).map(|h| {
+        (0..h.min(height - h))
+            .map(|y| {
+                (0..width)
+                    .filter(|&x| grid[h - 1 - y][x] != grid[h + y][x])
+                    .count()
+            })
+            .sum()
+    });
+    vertical.chain(horizontal).collect()
+}
+
+fn pattern(rng: &mut impl Rng, config: &Config) -> Vec<Vec<char>> {
+    let min = config.min_size.max(3);
+    let max = config.max_size.max(min);
+    loop {
+        let width = rng.gen_range(min..=max);
+        let height = rng.gen_range(min..=max);
+        let mut grid = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // a row reflection, and a column reflection that leaves the
+        // columns from 2 * column on without a mirror
+        let row = rng.gen_range(1..height);
+        let reach = row.min(height - row);
+        for y in 0..reach {
+            grid[row + y] = grid[row - 1 - y].clone();
+        }
+        let column = rng.gen_range(1..=(width - 1) / 2);
+        for line in grid.iter_mut() {
+            for x in 0..column {
+                line[2 * column - 1 - x] = line[x];
+            }
+        }
+        // the smudge breaks the row reflection only
+        let x = rng.gen_range(2 * column..width);
+        let y = rng.gen_range(row - reach..row + reach);
+        grid[y][x] = if grid[y][x] == '#' { '.' } else { '#' };
+        if rng.gen_bool(0.5) {
+            grid.iter_mut().for_each(|line| line.reverse());
+        }
+        if rng.gen_bool(0.5) {
+            grid = (0..width)
+                .map(|x| grid.iter().map(|line| line[x]).collect())
+                .collect();
+        }
+        let counts = mismatches(&grid);
+        if counts.iter().filter(|&&c| c == 0).count() == 1
+            && counts.iter().filter(|&&c| c == 1).count() == 1
+        {
+            return grid;
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    (0..config.patterns)
+        .map(|_| crate::common::lines(&pattern(rng, config)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_13::Day13;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(13), &Config::sized(30));
+        assert_eq!(input.split("\n\n").count(), 30);
+        let parsed = Day13::parse(&input).unwrap();
+        assert!(Day13::part1(&parsed).is_ok());
+        assert!(Day13::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+//! Platform of round rocks `O` and cube rocks `#`.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the platform.
+    pub size: usize,
+    pub round: f64,
+    pub cubes: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 100,
+            round: 0.2,
+            cubes: 0.1,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let grid = (0..config.size)
+        .map(|_| {
+            (0..config.size)
+                .map(|_| {
+                    let roll = rng.gen::<f64>();
+                    if roll < config.round {
+                        'O'
+                    } else if roll < config.round + config.cubes {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_14::Day14;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(14), &Config::sized(20));
+        let parsed = Day14::parse(&input).unwrap();
+        assert!(Day14::part1(&parsed).is_ok());
+        assert!(Day14::part2(&parsed).is_ok());
+    }
+}
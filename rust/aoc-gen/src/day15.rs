@@ -0,0 +1,68 @@
+//! Initialization sequence, comma separated steps such as `rn=1` and `cm-`
+//! on one line.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    pub steps: usize,
+    /// Different lens labels used by the steps.
+    pub labels: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            steps: 4000,
+            labels: 500,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(steps: usize) -> Self {
+        Config {
+            steps,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut taken = HashSet::new();
+    let labels = (0..config.labels.max(1))
+        .map(|_| {
+            let len = rng.gen_range(2..=6);
+            common::unique_name(rng, len, &mut taken)
+        })
+        .collect::<Vec<_>>();
+    let steps = (0..config.steps)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.6) {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            } else {
+                format!("{}-", label)
+            }
+        })
+        .collect::<Vec<_>>();
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_15::Day15;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(15), &Config::sized(100));
+        let parsed = Day15::parse(&input).unwrap();
+        assert!(Day15::part1(&parsed).is_ok());
+        assert!(Day15::part2(&parsed).is_ok());
+    }
+}
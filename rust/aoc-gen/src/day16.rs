@@ -0,0 +1,63 @@
+//! Contraption of mirrors `/` `\` and splitters `|` `-`.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the contraption.
+    pub size: usize,
+    /// Chance of a tile holding a mirror or a splitter.
+    pub devices: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 110,
+            devices: 0.1,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+const DEVICES: &[char] = &['/', '\\', '|', '-'];
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let grid = (0..config.size)
+        .map(|_| {
+            (0..config.size)
+                .map(|_| {
+                    if rng.gen_bool(config.devices) {
+                        DEVICES[rng.gen_range(0..DEVICES.len())]
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_16::Day16;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(16), &Config::sized(20));
+        let parsed = Day16::parse(&input).unwrap();
+        assert!(Day16::part1(&parsed).unwrap() <= Day16::part2(&parsed).unwrap());
+    }
+}
@@ -0,0 +1,48 @@
+//! City blocks, the heat loss of each one as a digit from 1 to 9.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the city, at least 5 for the ultra crucible.
+    pub size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { size: 141 }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config { size }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let grid = (0..config.size)
+        .map(|_| {
+            (0..config.size)
+                .map(|_| rng.gen_range('1'..='9'))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_17::Day17;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(17), &Config::sized(15));
+        let parsed = Day17::parse(&input).unwrap();
+        assert!(Day17::part1(&parsed).is_ok());
+        assert!(Day17::part2(&parsed).is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+//! Dig plan, `R 6 (#70c710)`. The directions and the colours each trace a
+//! closed loop that never touches itself, the colours' a rotated copy of the
+//! first one with much longer sides.
+
+use std::collections::{BTreeSet, HashMap};
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height, in cells, of the tree the loop goes around.
+    pub size: usize,
+    /// Longest step between two neighbouring corners of the plan.
+    pub max_step: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 14,
+            max_step: 4,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+/// Spreads the distinct values apart by random gaps of at least two, keeping
+/// their order, so the loop stays closed and the trench never touches itself.
+fn spread(
+    rng: &mut impl Rng,
+    values: impl Iterator<Item = i64>,
+    max_gap: i64,
+) -> HashMap<i64, i64> {
+    let mut position = 0;
+    values
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|value| {
+            position += rng.gen_range(2..=max_gap.max(2));
+            (value, position)
+        })
+        .collect()
+}
+
+fn sides(rng: &mut impl Rng, corners: &[(i64, i64)], max_gap: i64) -> Vec<(char, i64)> {
+    let xs = spread(rng, corners.iter().map(|c| c.0), max_gap);
+    let ys = spread(rng, corners.iter().map(|c| c.1), max_gap);
+    (0..corners.len())
+        .map(|i| {
+            let (x, y) = corners[i];
+            let (nx, ny) = corners[(i + 1) % corners.len()];
+            let (dx, dy) = (xs[&nx] - xs[&x], ys[&ny] - ys[&y]);
+            match (dx.signum(), dy.signum()) {
+                (1, 0) => ('R', dx),
+                (-1, 0) => ('L', -dx),
+                (0, 1) => ('D', dy),
+                _ => ('U', -dy),
+            }
+        })
+        .collect()
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size.max(1);
+    let corners = common::corners(&common::rectilinear_loop(rng, size, size));
+    let plan = sides(rng, &corners, config.max_step);
+    // a quarter turn, the longest side still fits in five hex digits
+    let rotated = corners.iter().map(|&(x, y)| (-y, x)).collect::<Vec<_>>();
+    let colors = sides(rng, &rotated, 0xfffff / (2 * size as i64));
+    let mut input = String::new();
+    for ((direction, length), (color, distance)) in plan.into_iter().zip(colors) {
+        let digit = match color {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3,
+        };
+        input.push_str(&format!(
+            "{} {} (#{:05x}{})\n",
+            direction, length, distance, digit
+        ));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_18::Day18;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(18), &Config::sized(5));
+        let parsed = Day18::parse(&input).unwrap();
+        assert!(Day18::part1(&parsed).unwrap() > 0);
+        assert!(Day18::part2(&parsed).unwrap() > Day18::part1(&parsed).unwrap());
+    }
+}
@@ -0,0 +1,93 @@
+//! Workflows and part ratings separated by a blank line. The workflows form
+//! a tree from `in`, so every part ends accepted or rejected.
+
+use std::collections::{HashSet, VecDeque};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::common;
+
+pub struct Config {
+    pub workflows: usize,
+    pub parts: usize,
+    pub max_rules: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            workflows: 550,
+            parts: 200,
+            max_rules: 3,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(workflows: usize) -> Self {
+        Config {
+            workflows,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut taken = HashSet::from(["in".to_owned()]);
+    let mut pending = VecDeque::from(["in".to_owned()]);
+    let mut left = config.workflows.max(1) - 1;
+    let mut workflows = Vec::new();
+    while let Some(name) = pending.pop_front() {
+        let rules = rng.gen_range(1..=config.max_rules.max(1));
+        let mut targets = Vec::new();
+        for i in 0..=rules {
+            // the last workflow to write keeps the tree growing until there
+            // are enough of them
+            let grow = left > 0 && (rng.gen_bool(0.6) || (pending.is_empty() && i == 0));
+            if grow {
+                left -= 1;
+                let len = rng.gen_range(2..=3);
+                let child = common::unique_name(rng, len, &mut taken);
+                pending.push_back(child.clone());
+                targets.push(child);
+            } else {
+                targets.push(if rng.gen_bool(0.5) { "A" } else { "R" }.to_owned());
+            }
+        }
+        let fallback = targets.pop().expect("hay al menos un destino");
+        let mut body = targets
+            .into_iter()
+            .map(|target| {
+                let category = ['x', 'm', 'a', 's'][rng.gen_range(0..4)];
+                let op = if rng.gen_bool(0.5) { '<' } else { '>' };
+                format!("{}{}{}:{}", category, op, rng.gen_range(2..4000), target)
+            })
+            .collect::<Vec<_>>();
+        body.push(fallback);
+        workflows.push(format!("{}{{{}}}", name, body.join(",")));
+    }
+    workflows.shuffle(rng);
+    let mut input = workflows.join("\n");
+    input.push_str("\n\n");
+    for _ in 0..config.parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        input.push_str(&format!("{{x={},m={},a={},s={}}}\n", x, m, a, s));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_19::Day19;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(19), &Config::sized(40));
+        assert_eq!(input.split("\n\n").next().unwrap().lines().count(), 40);
+        let parsed = Day19::parse(&input).unwrap();
+        assert!(Day19::part1(&parsed).is_ok());
+        assert!(Day19::part2(&parsed).is_ok());
+    }
+}
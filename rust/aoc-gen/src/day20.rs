@@ -0,0 +1,115 @@
+//! Module configuration, `%a -> b, c`. Like the real ones, the broadcaster
+//! starts a few binary counters of flip-flops, each resets at a prime with a
+//! conjunction, and `rx` gets a low pulse when all of them reset together.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::common;
+
+pub struct Config {
+    pub counters: usize,
+    /// Flip-flops of each counter, its period is a prime below `2^bits`.
+    pub bits: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            counters: 4,
+            bits: 12,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(counters: usize) -> Self {
+        Config {
+            counters,
+            ..Default::default()
+        }
+    }
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+/// Distinct primes with the highest of `bits` bits set, one per counter.
+pub fn periods(rng: &mut impl Rng, config: &Config) -> Vec<u64> {
+    let bits = config.bits.clamp(2, 40);
+    let primes = ((1u64 << (bits - 1))..(1u64 << bits))
+        .filter(|&n| is_prime(n))
+        .take(10_000)
+        .collect::<Vec<_>>();
+    primes
+        .choose_multiple(rng, config.counters)
+        .copied()
+        .collect()
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let mut taken = HashSet::from(["rx".to_owned()]);
+    let mut name = |rng: &mut _| common::unique_name(rng, 2, &mut taken);
+    let last = name(rng);
+    let mut lines = vec![format!("&{} -> rx", last)];
+    let mut starts = Vec::new();
+    for period in periods(rng, config) {
+        let bits = 64 - period.leading_zeros() as usize;
+        let flip_flops = (0..bits).map(|_| name(rng)).collect::<Vec<_>>();
+        let hub = name(rng);
+        let inverter = name(rng);
+        // flip-flops whose bit is set tell the hub, the hub resets the rest
+        // and the first one, which carries the count back to zero
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                destinations.push(next.clone());
+            }
+            if period >> bit & 1 == 1 {
+                destinations.push(hub.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            destinations.shuffle(rng);
+            lines.push(format!("%{} -> {}", flip_flop, destinations.join(", ")));
+        }
+        resets.push(inverter.clone());
+        resets.shuffle(rng);
+        lines.push(format!("&{} -> {}", hub, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_20::Day20;
+
+    #[test]
+    fn test_generate() {
+        let config = Config::sized(3);
+        let input = generate(&mut crate::rng(20), &config);
+        let mut rng = crate::rng(20);
+        // the same draws as generate up to the periods
+        let mut taken = HashSet::from(["rx".to_owned()]);
+        common::unique_name(&mut rng, 2, &mut taken);
+        let periods = periods(&mut rng, &config);
+        let parsed = Day20::parse(&input).unwrap();
+        assert!(Day20::part1(&parsed).is_ok());
+        assert_eq!(
+            Day20::part2(&parsed).unwrap(),
+            periods.iter().product::<u64>()
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Garden of plots and rocks with `S` in the middle. Like the real ones the
+//! edges and the row and column of the start are free of rocks, which part
+//! 2's formula relies on.
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the garden, odd so `S` is in the middle. Part 2
+    /// only works for sizes its 26501365 steps end on the edge of a copy
+    /// with, like 131.
+    pub size: usize,
+    pub rocks: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 131,
+            rocks: 0.1,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size.max(1) | 1;
+    let middle = size / 2;
+    let free = |i: usize| i == 0 || i == middle || i == size - 1;
+    let mut grid = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if !free(x) && !free(y) && rng.gen_bool(config.rocks) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    grid[middle][middle] = 'S';
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_21::Day21;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(21), &Config::default());
+        let parsed = Day21::parse(&input).unwrap();
+        assert!(Day21::part1(&parsed).is_ok());
+        assert!(Day21::part2(&parsed).is_ok());
+        let input = generate(&mut crate::rng(21), &Config::sized(10));
+        assert_eq!(input.lines().count(), 11);
+    }
+}
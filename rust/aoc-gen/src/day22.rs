@@ -0,0 +1,90 @@
+//! Snapshot of falling bricks, `1,0,1~1,2,1`. Bricks are straight and never
+//! overlap, as in the real snapshots.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+pub struct Config {
+    pub bricks: usize,
+    /// Width and depth of the area the bricks fall on.
+    pub area: usize,
+    /// Longest brick, in cubes.
+    pub max_len: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            bricks: 1300,
+            area: 10,
+            max_len: 5,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(bricks: usize) -> Self {
+        Config {
+            bricks,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let area = config.area.max(1);
+    // room for every brick twice over, so placing them never gets stuck
+    let max_z = 2 * config.bricks * config.max_len.max(1) / (area * area) + 10;
+    let mut taken = HashSet::new();
+    let mut input = String::new();
+    let mut placed = 0;
+    while placed < config.bricks {
+        let len = rng.gen_range(0..config.max_len.max(1));
+        let axis = rng.gen_range(0..3);
+        let mut start = [
+            rng.gen_range(0..area),
+            rng.gen_range(0..area),
+            rng.gen_range(1..=max_z),
+        ];
+        // the brick must fit in the area
+        if axis < 2 {
+            start[axis] = start[axis].min(area.saturating_sub(len + 1));
+        }
+        let mut end = start;
+        end[axis] += len;
+        let cubes = (0..=len)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect::<Vec<_>>();
+        if end[0] >= area || end[1] >= area || cubes.iter().any(|cube| taken.contains(cube)) {
+            continue;
+        }
+        taken.extend(cubes);
+        input.push_str(&format!(
+            "{},{},{}~{},{},{}\n",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+        placed += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_22::Day22;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut crate::rng(22), &Config::sized(100));
+        assert_eq!(input.lines().count(), 100);
+        let parsed = Day22::parse(&input).unwrap();
+        assert!(Day22::part1(&parsed).unwrap() <= 100);
+        assert!(Day22::part2(&parsed).is_ok());
+    }
+}
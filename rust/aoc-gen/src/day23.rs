@@ -0,0 +1,127 @@
+//! Hiking map, a maze of paths `.` through the forest `#` from the top left
+//! to the bottom right. Slopes next to every junction point away from the
+//! start, so part 1 can always reach the end.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rand::Rng;
+
+use crate::common;
+
+pub struct Config {
+    /// Width and height of the map, odd.
+    pub size: usize,
+    /// Walls knocked down on top of a maze with a single path between any
+    /// two points. Each one adds a loop, part 2 gets exponentially slower.
+    pub shortcuts: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 41,
+            shortcuts: 12,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(size: usize) -> Self {
+        Config {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let size = config.size.max(3) | 1;
+    let cells = (size - 1) / 2;
+    let mut grid = vec![vec!['#'; size]; size];
+    // cell (x, y) of the maze is the tile (2x + 1, 2y + 1)
+    let open = |grid: &mut Vec<Vec<char>>, (ax, ay): (usize, usize), (bx, by): (usize, usize)| {
+        grid[2 * ay + 1][2 * ax + 1] = '.';
+        grid[2 * by + 1][2 * bx + 1] = '.';
+        grid[ay + by + 1][ax + bx + 1] = '.';
+    };
+    for (a, b) in common::spanning_tree(rng, cells, cells) {
+        open(&mut grid, a, b);
+    }
+    if cells > 1 {
+        for _ in 0..config.shortcuts {
+            let a = (rng.gen_range(0..cells - 1), rng.gen_range(0..cells - 1));
+            let b = if rng.gen_bool(0.5) {
+                (a.0 + 1, a.1)
+            } else {
+                (a.0, a.1 + 1)
+            };
+            open(&mut grid, a, b);
+        }
+    }
+    let start = (1, 0);
+    let end = (size - 2, size - 1);
+    grid[start.1][start.0] = '.';
+    grid[end.1][end.0] = '.';
+
+    let neighbours = |(x, y): (usize, usize)| {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+            })
+            .filter(|&(x, y)| x < size && y < size)
+    };
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(tile) = queue.pop_front() {
+        for next in neighbours(tile) {
+            if grid[next.1][next.0] != '#' && !distances.contains_key(&next) {
+                distances.insert(next, distances[&tile] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    let junctions = distances
+        .keys()
+        .copied()
+        .filter(|&tile| neighbours(tile).filter(|&(x, y)| grid[y][x] != '#').count() > 2)
+        .collect::<HashSet<_>>();
+    for &junction in &junctions {
+        for (x, y) in neighbours(junction) {
+            if grid[y][x] == '#' || (x, y) == start || (x, y) == end {
+                continue;
+            }
+            // downhill, away from whichever side is closer to the start
+            let (from, to) = if distances[&(x, y)] > distances[&junction] {
+                (junction, (x, y))
+            } else {
+                ((x, y), junction)
+            };
+            grid[y][x] = match (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, 1) => 'v',
+                _ => '^',
+            };
+        }
+    }
+    common::lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_23::Day23;
+
+    #[test]
+    fn test_generate() {
+        let config = Config {
+            size: 21,
+            shortcuts: 4,
+        };
+        let input = generate(&mut crate::rng(23), &config);
+        let parsed = Day23::parse(&input).unwrap();
+        assert!(Day23::part1(&parsed).unwrap() <= Day23::part2(&parsed).unwrap());
+    }
+}
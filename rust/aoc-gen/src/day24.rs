@@ -0,0 +1,82 @@
+//! Hailstones, `19, 13, 30 @ -2, 1, -2`, placed so a single rock thrown
+//! from some point hits every one of them, as part 2 expects.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+pub struct Config {
+    pub hailstones: usize,
+    pub max_speed: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            hailstones: 300,
+            max_speed: 300,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(hailstones: usize) -> Self {
+        Config {
+            hailstones,
+            ..Default::default()
+        }
+    }
+}
+
+/// Position and velocity of the rock that hits every hailstone.
+pub fn rock(rng: &mut impl Rng, config: &Config) -> ([i64; 3], [i64; 3]) {
+    let position = [(); 3].map(|_| rng.gen_range(200_000_000_000_000..300_000_000_000_000));
+    let velocity = [(); 3].map(|_| rng.gen_range(-config.max_speed..=config.max_speed));
+    (position, velocity)
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let (position, velocity) = rock(rng, config);
+    let mut times = HashSet::new();
+    let mut input = String::new();
+    while times.len() < config.hailstones {
+        let time = rng.gen_range(100_000_000_000..1_000_000_000_000i64);
+        let speed = [(); 3].map(|_| loop {
+            let speed = rng.gen_range(-config.max_speed..=config.max_speed);
+            // no hailstone stands still along an axis, nor moves like the rock
+            if speed != 0 {
+                break speed;
+            }
+        });
+        if (0..3).any(|i| speed[i] == velocity[i]) || !times.insert(time) {
+            continue;
+        }
+        // where it has to be now to meet the rock at `time`
+        let start = [0, 1, 2].map(|i| position[i] + time * (velocity[i] - speed[i]));
+        input.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            start[0], start[1], start[2], speed[0], speed[1], speed[2]
+        ));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_24::Day24;
+
+    #[test]
+    fn test_generate() {
+        let config = Config::sized(20);
+        let input = generate(&mut crate::rng(24), &config);
+        let (position, _) = rock(&mut crate::rng(24), &config);
+        let parsed = Day24::parse(&input).unwrap();
+        assert!(Day24::part1(&parsed).is_ok());
+        assert_eq!(
+            Day24::part2(&parsed).unwrap(),
+            position.iter().sum::<i64>() as i128
+        );
+    }
+}
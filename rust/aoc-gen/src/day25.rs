@@ -0,0 +1,96 @@
+//! Wiring diagram, `jqt: rhn xhk nvd`. Two well connected groups of
+//! components joined by exactly three wires.
+
+use std::collections::{BTreeSet, HashSet};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::common;
+
+pub struct Config {
+    pub nodes: usize,
+    /// Wires from each component to others of its group.
+    pub wires: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            nodes: 1500,
+            wires: 4,
+        }
+    }
+}
+
+impl Config {
+    pub fn sized(nodes: usize) -> Self {
+        Config {
+            nodes,
+            ..Default::default()
+        }
+    }
+}
+
+/// Size of the first group, the answer is it times the rest.
+pub fn split(rng: &mut impl Rng, config: &Config) -> usize {
+    let nodes = config.nodes.max(8);
+    rng.gen_range(nodes * 2 / 5..=nodes * 3 / 5)
+}
+
+pub fn generate(rng: &mut impl Rng, config: &Config) -> String {
+    let nodes = config.nodes.max(8);
+    let first = split(rng, config);
+    let mut taken = HashSet::new();
+    let names = (0..nodes)
+        .map(|_| common::unique_name(rng, 3, &mut taken))
+        .collect::<Vec<_>>();
+    let mut wires = BTreeSet::new();
+    for group in [0..first, first..nodes] {
+        let members = group.collect::<Vec<_>>();
+        // a ring keeps each group connected, random wires make it hard to cut
+        for (i, &a) in members.iter().enumerate() {
+            let b = members[(i + 1) % members.len()];
+            wires.insert((a.min(b), a.max(b)));
+            for &b in members.choose_multiple(rng, config.wires) {
+                if a != b {
+                    wires.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+    let mut bridges = BTreeSet::new();
+    while bridges.len() < 3 {
+        bridges.insert((rng.gen_range(0..first), rng.gen_range(first..nodes)));
+    }
+    wires.extend(bridges);
+    // each wire is listed once, on the line of either of its ends
+    let mut lines = vec![Vec::new(); nodes];
+    for (a, b) in wires {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines[from].push(names[to].clone());
+    }
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, to)| !to.is_empty())
+        .map(|(from, to)| format!("{}: {}", names[from], to.join(" ")))
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use day_25::Day25;
+
+    #[test]
+    fn test_generate() {
+        let config = Config::sized(60);
+        let input = generate(&mut crate::rng(25), &config);
+        let first = split(&mut crate::rng(25), &config);
+        let parsed = Day25::parse(&input).unwrap();
+        assert_eq!(Day25::part1(&parsed).unwrap(), first * (60 - first));
+    }
+}
@@ -0,0 +1,131 @@
+//! Seeded generators of puzzle inputs in each day's exact format.
+//!
+//! Real inputs can't be shared and the examples are tiny, so these write
+//! inputs of any size for benchmarks, fuzzing and property tests. Every day
+//! has a module with a `Config` of size knobs, whose `Default` is about the
+//! size of a real input, and a `generate` function. The same seed and config
+//! always give the same input.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod common;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Random generator used by every day, seeded so inputs can be reproduced
+/// on any platform.
+pub fn rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Writes an input for `day` with its main size knob set to `size`, see each
+/// day's `Config::sized`. `None` if there is no such day.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut rng(seed);
+    let input = match day {
+        1 => day01::generate(rng, &day01::Config::sized(size)),
+        2 => day02::generate(rng, &day02::Config::sized(size)),
+        3 => day03::generate(rng, &day03::Config::sized(size)),
+        4 => day04::generate(rng, &day04::Config::sized(size)),
+        5 => day05::generate(rng, &day05::Config::sized(size)),
+        6 => day06::generate(rng, &day06::Config::sized(size)),
+        7 => day07::generate(rng, &day07::Config::sized(size)),
+        8 => day08::generate(rng, &day08::Config::sized(size)),
+        9 => day09::generate(rng, &day09::Config::sized(size)),
+        10 => day10::generate(rng, &day10::Config::sized(size)),
+        11 => day11::generate(rng, &day11::Config::sized(size)),
+        12 => day12::generate(rng, &day12::Config::sized(size)),
+        13 => day13::generate(rng, &day13::Config::sized(size)),
+        14 => day14::generate(rng, &day14::Config::sized(size)),
+        15 => day15::generate(rng, &day15::Config::sized(size)),
+        16 => day16::generate(rng, &day16::Config::sized(size)),
+        17 => day17::generate(rng, &day17::Config::sized(size)),
+        18 => day18::generate(rng, &day18::Config::sized(size)),
+        19 => day19::generate(rng, &day19::Config::sized(size)),
+        20 => day20::generate(rng, &day20::Config::sized(size)),
+        21 => day21::generate(rng, &day21::Config::sized(size)),
+        22 => day22::generate(rng, &day22::Config::sized(size)),
+        23 => day23::generate(rng, &day23::Config::sized(size)),
+        24 => day24::generate(rng, &day24::Config::sized(size)),
+        25 => day25::generate(rng, &day25::Config::sized(size)),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// The main size knob of each day with its `Default`, a real input's size.
+pub fn default_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 => day01::Config::default().lines,
+        2 => day02::Config::default().games,
+        3 => day03::Config::default().size,
+        4 => day04::Config::default().cards,
+        5 => day05::Config::default().segments,
+        6 => day06::Config::default().races,
+        7 => day07::Config::default().hands,
+        8 => day08::Config::default().ghosts,
+        9 => day09::Config::default().lines,
+        10 => day10::Config::default().size,
+        11 => day11::Config::default().size,
+        12 => day12::Config::default().records,
+        13 => day13::Config::default().patterns,
+        14 => day14::Config::default().size,
+        15 => day15::Config::default().steps,
+        16 => day16::Config::default().size,
+        17 => day17::Config::default().size,
+        18 => day18::Config::default().size,
+        19 => day19::Config::default().workflows,
+        20 => day20::Config::default().counters,
+        21 => day21::Config::default().size,
+        22 => day22::Config::default().bricks,
+        23 => day23::Config::default().size,
+        24 => day24::Config::default().hailstones,
+        25 => day25::Config::default().nodes,
+        _ => return None,
+    };
+    Some(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        for day in 1..=25 {
+            let size = default_size(day).unwrap();
+            assert_eq!(
+                generate(day, 7, size),
+                generate(day, 7, size),
+                "day {}",
+                day
+            );
+        }
+        assert_ne!(generate(1, 1, 10), generate(1, 2, 10));
+        assert_eq!(generate(26, 1, 10), None);
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fs, io::Write, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+
+/// Writes a random puzzle input for a day, the same for the same seed.
+#[derive(Parser)]
+struct Args {
+    /// Day to generate (1-25).
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+    /// Main size knob of the day, see its `Config::sized`. Defaults to the
+    /// size of a real input.
+    #[arg(short = 'n', long)]
+    size: Option<usize>,
+    /// File to write, stdout if not given.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let size = args
+        .size
+        .or_else(|| aoc_gen::default_size(args.day))
+        .expect("los dias van del 1 al 25");
+    let input = aoc_gen::generate(args.day, args.seed, size).expect("los dias van del 1 al 25");
+    let written = match &args.output {
+        Some(path) => fs::write(path, input),
+        None => std::io::stdout().write_all(input.as_bytes()),
+    };
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("no se pudo escribir la entrada: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
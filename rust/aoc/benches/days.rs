@@ -0,0 +1,218 @@
+//! Parse, part 1 and part 2 of every day, on the day's own input (`x1`) and
+//! on copies of it made bigger where the puzzle still makes sense repeated.
+//!
+//! `cargo bench -p aoc` compares against the previous run. To compare an
+//! alternative implementation save a baseline first and bench against it:
+//!
+//! ```text
+//! cargo bench -p aoc -- --save-baseline antes
+//! cargo bench -p aoc -- --baseline antes
+//! ```
+//!
+//! A filter picks the days to run, e.g. `cargo bench -p aoc -- day-16`.
+//!
+//! Every day also runs on inputs from `aoc-gen` of growing size, in groups
+//! named `day-NN-gen` with the size knob as the id. That is the only way to
+//! grow the days whose input doesn't make sense repeated.
+
+use aoc_core::{input, Solution};
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+    SamplingMode, Throughput,
+};
+
+/// How a day's input can be made `n` times bigger.
+#[derive(Clone, Copy)]
+enum Scale {
+    /// The input only makes sense as it is.
+    None,
+    /// Independent lines, repeated one after the other.
+    Lines,
+    /// Independent blocks separated by a blank line.
+    Blocks,
+    /// Independent steps separated by commas.
+    Steps,
+    /// A grid, repeated `n` times in each direction.
+    Tile,
+}
+
+const SIZES: &[usize] = &[1, 2, 4];
+/// Sizes for the days that already take hundreds of milliseconds on their
+/// own input.
+const SLOW: &[usize] = &[1, 2];
+
+fn scaled(input: &str, scale: Scale, n: usize) -> String {
+    let repeat = |pieces: Vec<&str>, separator: &str| {
+        let total = pieces.len() * n;
+        pieces
+            .into_iter()
+            .cycle()
+            .take(total)
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+    match scale {
+        Scale::None => input.to_owned(),
+        Scale::Lines => repeat(input.lines().collect(), "\n"),
+        Scale::Blocks => repeat(input.trim_end().split("\n\n").collect(), "\n\n"),
+        Scale::Steps => repeat(input.trim_end().split(',').collect(), ","),
+        Scale::Tile => {
+            let rows = input.lines().map(|row| row.repeat(n)).collect::<Vec<_>>();
+            repeat(rows.iter().map(String::as_str).collect(), "\n")
+        }
+    }
+}
+
+fn real_input(day: u8) -> String {
+    let day = aoc::find(day).expect("el dia no esta registrado");
+    input::read(day.input).expect("no se pudo leer la entrada")
+}
+
+// Slow days get fewer samples, one run each, or a full bench takes hours.
+fn slow(group: &mut BenchmarkGroup<'_, WallTime>) {
+    group.sample_size(10).sampling_mode(SamplingMode::Flat);
+}
+
+fn bench<S: Solution>(c: &mut Criterion, scale: Scale, sizes: &[usize]) {
+    let parts = aoc::find(S::DAY).map_or(2, |day| day.parts);
+    let input = real_input(S::DAY);
+    let mut group = c.benchmark_group(format!("day-{:02}", S::DAY));
+    if sizes == SLOW {
+        slow(&mut group);
+    }
+    let sizes = match scale {
+        Scale::None => &[1],
+        _ => sizes,
+    };
+    for &n in sizes {
+        let input = scaled(&input, scale, n);
+        let size = format!("x{}", n);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("parse", &size), &input, |b, input| {
+            b.iter(|| S::parse(input))
+        });
+        let parsed = S::parse(&input).expect("la entrada deberia parsear");
+        group.bench_function(BenchmarkId::new("part1", &size), |b| {
+            b.iter(|| S::part1(&parsed))
+        });
+        if parts > 1 {
+            group.bench_function(BenchmarkId::new("part2", &size), |b| {
+                b.iter(|| S::part2(&parsed))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench::<day_01::Day01>(c, Scale::Lines, SIZES);
+    bench::<day_02::Day02>(c, Scale::Lines, SIZES);
+    // Stacked vertically only, side by side numbers would join.
+    bench::<day_03::Day03>(c, Scale::Lines, SIZES);
+    bench::<day_04::Day04>(c, Scale::Lines, SIZES);
+    bench::<day_05::Day05>(c, Scale::None, SIZES);
+    bench::<day_06::Day06>(c, Scale::None, SIZES);
+    bench::<day_07::Day07>(c, Scale::Lines, SIZES);
+    bench::<day_08::Day08>(c, Scale::None, SIZES);
+    bench::<day_09::Day09>(c, Scale::Lines, SIZES);
+    bench::<day_10::Day10>(c, Scale::None, SIZES);
+    bench::<day_11::Day11>(c, Scale::Tile, SIZES);
+    bench::<day_12::Day12>(c, Scale::Lines, SLOW);
+    bench::<day_13::Day13>(c, Scale::Blocks, SIZES);
+    bench::<day_14::Day14>(c, Scale::Tile, SIZES);
+    bench::<day_15::Day15>(c, Scale::Steps, SIZES);
+    bench::<day_16::Day16>(c, Scale::Tile, SLOW);
+    bench::<day_17::Day17>(c, Scale::Tile, SLOW);
+    bench::<day_18::Day18>(c, Scale::None, SIZES);
+    bench::<day_19::Day19>(c, Scale::None, SIZES);
+    bench::<day_20::Day20>(c, Scale::None, SIZES);
+    // Part 2's 26501365 steps only end on the edge of a copy for some sizes.
+    bench::<day_21::Day21>(c, Scale::None, SIZES);
+    // Copies land on top of each other, part 2 gets out of hand past x2.
+    bench::<day_22::Day22>(c, Scale::Lines, SLOW);
+    bench::<day_23::Day23>(c, Scale::None, SLOW);
+    bench::<day_24::Day24>(c, Scale::Lines, SIZES);
+    bench::<day_25::Day25>(c, Scale::None, SLOW);
+}
+
+// A day on generated inputs of growing size, always with the same seed.
+fn generated<S: Solution>(c: &mut Criterion, sizes: &[usize]) {
+    let parts = aoc::find(S::DAY).map_or(2, |day| day.parts);
+    let mut group = c.benchmark_group(format!("day-{:02}-gen", S::DAY));
+    slow(&mut group);
+    for &n in sizes {
+        let input = aoc_gen::generate(S::DAY, 0, n).expect("el dia no tiene generador");
+        let parsed = S::parse(&input).expect("la entrada generada deberia parsear");
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(BenchmarkId::new("part1", n), |b| {
+            b.iter(|| S::part1(&parsed))
+        });
+        if parts > 1 {
+            group.bench_function(BenchmarkId::new("part2", n), |b| {
+                b.iter(|| S::part2(&parsed))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn generated_days(c: &mut Criterion) {
+    generated::<day_01::Day01>(c, &[1000, 4000, 16000]);
+    generated::<day_02::Day02>(c, &[100, 400, 1600]);
+    generated::<day_03::Day03>(c, &[140, 280, 560]);
+    generated::<day_04::Day04>(c, &[200, 800, 3200]);
+    generated::<day_05::Day05>(c, &[40, 160, 640]);
+    // The joined up races of part 2 have to fit in a u128.
+    generated::<day_06::Day06>(c, &[2, 4, 8]);
+    generated::<day_07::Day07>(c, &[1000, 4000, 16000]);
+    // At most 12 ghosts, see the generator.
+    generated::<day_08::Day08>(c, &[3, 6, 12]);
+    generated::<day_09::Day09>(c, &[200, 800, 3200]);
+    generated::<day_10::Day10>(c, &[140, 280, 560]);
+    // Every pair of galaxies, the time grows with the fourth power of the size.
+    generated::<day_11::Day11>(c, &[70, 140, 280]);
+    generated::<day_12::Day12>(c, &[250, 1000]);
+    generated::<day_13::Day13>(c, &[100, 400, 1600]);
+    generated::<day_14::Day14>(c, &[50, 100, 200]);
+    generated::<day_15::Day15>(c, &[4000, 16000, 64000]);
+    generated::<day_16::Day16>(c, &[110, 220]);
+    generated::<day_17::Day17>(c, &[71, 141]);
+    generated::<day_18::Day18>(c, &[14, 28, 56]);
+    // Workflow names have two or three letters, the generator runs out of two
+    // letter ones at about 1300 workflows.
+    generated::<day_19::Day19>(c, &[275, 550, 1100]);
+    // Each counter has a prime period, the LCM of many of them doesn't fit in
+    // a u64.
+    generated::<day_20::Day20>(c, &[1, 2, 4]);
+    // The only sizes part 2's 26501365 steps end on the edge of a copy with.
+    generated::<day_21::Day21>(c, &[131, 393]);
+    generated::<day_22::Day22>(c, &[400, 1300]);
+    // The number of paths grows exponentially with the size of the maze.
+    generated::<day_23::Day23>(c, &[21, 41]);
+    generated::<day_24::Day24>(c, &[300, 1200]);
+    generated::<day_25::Day25>(c, &[500, 1500]);
+}
+
+// Energizes the contraption from every edge, one beam after the other or
+// with rayon.
+fn day_16_par(c: &mut Criterion) {
+    let input = real_input(16);
+    let mut group = c.benchmark_group("day-16-part2");
+    slow(&mut group);
+    for &n in SLOW {
+        let input = scaled(&input, Scale::Tile, n);
+        let grid = day_16::Day16::parse(&input).expect("la entrada deberia parsear");
+        let size = format!("x{}", n);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("seq", &size), &grid, |b, grid| {
+            b.iter(|| day_16::process_part2(grid))
+        });
+        group.bench_with_input(BenchmarkId::new("par", &size), &grid, |b, grid| {
+            b.iter(|| day_16::process_part2_par(grid))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, days, generated_days, day_16_par);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! Every day's [`Solution`](aoc_core::Solution) in one list, for the runner
+//! and any other tool that wants to go through all of them.
+
+#![warn(missing_docs)]
+
+pub use aoc_core::solution::Day;
+
+// Registers a day with its input file, the answers live next to its manifest.
+macro_rules! day {
+    ($solution:ty, $day:literal, $input:literal) => {
+        Day::new::<$solution>(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../", $day, "/", $input),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../", $day, "/answers.txt"),
+        )
+    };
+}
+
+/// Every day in order, with its input and answers files and the parts it
+/// has.
+pub static DAYS: [Day; 25] = [
+    day!(day_01::Day01, "day-01", "src/input.txt"),
+    day!(day_02::Day02, "day-02", "src/input.txt"),
+    day!(day_03::Day03, "day-03", "src/input.txt"),
+    day!(day_04::Day04, "day-04", "src/input.txt"),
+    day!(day_05::Day05, "day-05", "src/input.txt"),
+    day!(day_06::Day06, "day-06", "src/input.txt"),
+    day!(day_07::Day07, "day-07", "src/input.txt"),
+    day!(day_08::Day08, "day-08", "src/input.txt"),
+    day!(day_09::Day09, "day-09", "src/input.txt"),
+    day!(day_10::Day10, "day-10", "src/input.txt").with_visualizer::<day_10::Day10>(),
+    day!(day_11::Day11, "day-11", "src/input.txt"),
+    day!(day_12::Day12, "day-12", "src/input.txt"),
+    day!(day_13::Day13, "day-13", "src/input.txt"),
+    day!(day_14::Day14, "day-14", "src/input.txt").with_visualizer::<day_14::Day14>(),
+    day!(day_15::Day15, "day-15", "src/input.txt"),
+    day!(day_16::Day16, "day-16", "src/input.txt").with_visualizer::<day_16::Day16>(),
+    day!(day_17::Day17, "day-17", "src/input.txt").with_visualizer::<day_17::Day17>(),
+    day!(day_18::Day18, "day-18", "src/input.txt").with_visualizer::<day_18::Day18>(),
+    day!(day_19::Day19, "day-19", "src/input.txt"),
+    day!(day_20::Day20, "day-20", "src/input.txt"),
+    day!(day_21::Day21, "day-21", "src/input.txt").with_visualizer::<day_21::Day21>(),
+    day!(day_22::Day22, "day-22", "src/input.txt"),
+    day!(day_23::Day23, "day-23", "src/input.txt").with_visualizer::<day_23::Day23>(),
+    day!(day_24::Day24, "day-24", "src/input.txt"),
+    // Day 25 only has one puzzle.
+    day!(day_25::Day25, "day-25", "src/input.txt").with_parts(1),
+];
+
+/// The registered `day`, `None` outside 1 to 25.
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_day_registered() {
+        for day in 1..=25 {
+            assert_eq!(find(day).map(|d| d.day), Some(day));
+        }
+        assert!(find(26).is_none());
+        assert_eq!(find(25).map(|d| d.parts), Some(1));
+    }
+
+    #[test]
+    fn test_visualizers() {
+        let visualized: Vec<u8> = DAYS
+            .iter()
+            .filter(|d| d.frames("").is_some())
+            .map(|d| d.day)
+            .collect();
+        assert_eq!(visualized, [10, 14, 16, 17, 18, 21, 23]);
+    }
+}
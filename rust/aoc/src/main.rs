@@ -0,0 +1,359 @@
+use std::{path::PathBuf, process::ExitCode, thread, time::Duration};
+
+use aoc::{Day, DAYS};
+use aoc_core::{
+    answers::{self, Answers, Check, Status},
+    render,
+    solution::{Run, Timed},
+    Error,
+};
+use clap::{ArgGroup, Parser, ValueEnum};
+use serde::Serialize;
+
+/// Runs the Advent of Code 2023 solutions.
+#[derive(Parser)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .multiple(true)
+        .args(["day", "all", "check"])
+))]
+struct Args {
+    /// Day to run (1-25).
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Only run this part (1 or 2).
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input file, `-` reads stdin. Defaults to the day's own input.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Run every day on its own input.
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+    /// Compare answers on each day's own input against its `answers.txt`,
+    /// every day unless `--day` is given.
+    #[arg(short, long, conflicts_with = "input")]
+    check: bool,
+    /// How to print the answers, or the checks with `--check`.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Show how the day gets its answers instead of printing them, in the
+    /// terminal unless `--output` is given.
+    #[arg(short, long, requires = "day", conflicts_with_all = ["all", "check"])]
+    visualize: bool,
+    /// Save the visualization to a `.png` (last frame) or `.gif` file.
+    #[arg(short, long, requires = "visualize")]
+    output: Option<PathBuf>,
+    /// Pixels per grid cell in saved images.
+    #[arg(long, default_value_t = 4, requires = "output")]
+    scale: usize,
+    /// Milliseconds between frames.
+    #[arg(long, default_value_t = 100, requires = "visualize")]
+    delay: u64,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A line for each part, for people.
+    Text,
+    /// One JSON document, for scripts.
+    Json,
+}
+
+#[derive(Serialize)]
+struct ErrorReport {
+    stage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    message: String,
+    #[serde(skip)]
+    text: String,
+}
+
+impl ErrorReport {
+    fn new(error: &Error) -> Self {
+        ErrorReport {
+            stage: error.stage.to_string(),
+            line: error.line,
+            column: error.column,
+            message: error.message.clone(),
+            text: error.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Stage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorReport>,
+    time_ms: f64,
+}
+
+impl Stage {
+    fn new(timed: &Timed<String>) -> Self {
+        let (answer, error) = match &timed.result {
+            Ok(answer) => (Some(answer.clone()), None),
+            Err(error) => (None, Some(ErrorReport::new(error))),
+        };
+        Stage {
+            answer,
+            error,
+            time_ms: millis(timed.time),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Report {
+    day: u8,
+    parse: Stage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Stage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Stage>,
+}
+
+impl Report {
+    fn new(run: &Run) -> Self {
+        let part = |n| {
+            run.parts
+                .iter()
+                .find(|p| p.part == n)
+                .map(|p| Stage::new(&p.answer))
+        };
+        Report {
+            day: run.day,
+            parse: Stage {
+                answer: None,
+                error: run.parse.result.as_ref().err().map(ErrorReport::new),
+                time_ms: millis(run.parse.time),
+            },
+            part1: part(1),
+            part2: part(2),
+        }
+    }
+
+    fn failed(&self) -> bool {
+        [Some(&self.parse), self.part1.as_ref(), self.part2.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|stage| stage.error.is_some())
+    }
+
+    fn print(&self) {
+        match &self.parse.error {
+            Some(error) => println!("Day {:02} parse: {}", self.day, error.text),
+            None => println!("Day {:02} parse in {:.3}ms", self.day, self.parse.time_ms),
+        }
+        for (part, stage) in [(1, &self.part1), (2, &self.part2)] {
+            match stage {
+                Some(Stage {
+                    answer: Some(answer),
+                    time_ms,
+                    ..
+                }) => println!(
+                    "Day {:02} part {}: {} in {:.3}ms",
+                    self.day, part, answer, time_ms
+                ),
+                Some(Stage {
+                    error: Some(error), ..
+                }) => println!("Day {:02} part {}: {}", self.day, part, error.text),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CheckReport {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorReport>,
+}
+
+impl CheckReport {
+    fn new(check: &Check) -> Self {
+        CheckReport {
+            day: check.day,
+            part: check.part,
+            status: match check.status {
+                Status::Pass => "pass",
+                Status::Fail => "fail",
+                Status::Missing => "missing",
+            },
+            expected: check.expected.clone(),
+            actual: check.actual.clone().ok(),
+            error: check.actual.as_ref().err().map(ErrorReport::new),
+        }
+    }
+}
+
+fn print_check(check: &Check) {
+    let header = format!("Day {:02} part {}", check.day, check.part);
+    match (&check.status, &check.actual) {
+        (Status::Pass, _) => println!("{}: pass", header),
+        (Status::Missing, Ok(actual)) => println!("{}: missing (got {})", header, actual),
+        (Status::Missing, Err(error)) => println!("{}: missing ({})", header, error),
+        (Status::Fail, _) => {
+            println!("{}: FAIL", header);
+            for line in check.diff().unwrap_or_default().lines() {
+                println!("    {}", line);
+            }
+        }
+    }
+}
+
+// Regression mode: every selected day on its own input against its answers.
+fn check(selected: &[&Day], parts: &[u8], format: Format) -> ExitCode {
+    let mut checks = Vec::new();
+    for day in selected {
+        let parts: Vec<u8> = parts
+            .iter()
+            .copied()
+            .filter(|&part| part <= day.parts)
+            .collect();
+        let loaded = aoc_core::input::read(day.input)
+            .and_then(|input| Ok((input, Answers::read(day.answers)?)))
+            .map_err(|e| Error::read(day.day, e.to_string()));
+        let day_checks = match loaded {
+            Ok((input, known)) => answers::check(&day.run(&input, &parts), &known, &parts),
+            Err(error) => answers::unreadable(day.day, &error, &parts),
+        };
+        if let Format::Text = format {
+            day_checks.iter().for_each(print_check);
+        }
+        checks.extend(day_checks);
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let failed = count(Status::Fail);
+    match format {
+        Format::Text => println!(
+            "{} pass, {} fail, {} missing",
+            count(Status::Pass),
+            failed,
+            count(Status::Missing)
+        ),
+        Format::Json => {
+            let reports: Vec<CheckReport> = checks.iter().map(CheckReport::new).collect();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&reports).expect("reports are plain data")
+            );
+        }
+    }
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Visualization mode: frames to the terminal or to an image file.
+fn visualize(day: &Day, input: &str, args: &Args) -> ExitCode {
+    let frames = match day.frames(input) {
+        Some(Ok(frames)) => frames,
+        Some(Err(error)) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+        None => {
+            let message = "este dia no tiene visualizacion";
+            eprintln!("{}", Error::solve(day.day, message));
+            return ExitCode::FAILURE;
+        }
+    };
+    let delay = Duration::from_millis(args.delay);
+    let Some(path) = &args.output else {
+        for (i, frame) in frames.iter().enumerate() {
+            if i > 0 {
+                thread::sleep(delay);
+                // Back to the top left and clear, so frames replace each other.
+                print!("\x1b[H\x1b[2J");
+            }
+            print!("{}", frame.terminal());
+        }
+        return ExitCode::SUCCESS;
+    };
+    let saved = match path.extension().and_then(|e| e.to_str()) {
+        Some("png") => frames
+            .last()
+            .map_or(Ok(()), |frame| render::write_png(path, frame, args.scale)),
+        Some("gif") => render::write_gif(path, &frames, args.scale, delay),
+        _ => {
+            eprintln!("{}: la salida tiene que ser .png o .gif", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = saved {
+        eprintln!("no se pudo escribir {}: {}", path.display(), error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let selected: Vec<&Day> = match args.day {
+        Some(day) => vec![aoc::find(day).expect("every day from 1 to 25 is registered")],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if args.check {
+        return check(&selected, &parts, args.format);
+    }
+
+    let mut reports = Vec::new();
+    for day in selected {
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(day.input));
+        let input = match aoc_core::input::read(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                let message = format!("no se pudo leer {}: {}", path.display(), error);
+                eprintln!("{}", Error::read(day.day, message));
+                return ExitCode::FAILURE;
+            }
+        };
+        if args.visualize {
+            return visualize(day, &input, &args);
+        }
+        let report = Report::new(&day.run(&input, &parts));
+        if let Format::Text = args.format {
+            report.print();
+        }
+        reports.push(report);
+    }
+
+    if let Format::Json = args.format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&reports).expect("reports are plain data")
+        );
+    }
+    if reports.iter().any(Report::failed) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
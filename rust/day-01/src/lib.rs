@@ -0,0 +1,252 @@
+//! Day 1: Trebuchet?!. Calibration values hidden in lines of text, with the
+//! digits spelled out in any language.
+
+#![warn(missing_docs)]
+
+use std::fmt;
+
+use aho_corasick::{AhoCorasick, Match};
+use aoc_core::{parse, Error, Solution};
+
+/// Calibration documents read line by line.
+pub mod reader;
+
+pub use reader::{Mode, Report, Skipped, SAMPLES};
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+/// The words that count as digits besides `0` to `9`, searched all at once
+/// with an Aho-Corasick automaton.
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Dictionary {
+    /// A dictionary with `words` and the digit each one stands for. Words
+    /// may not be empty and their values have to be digits. When two
+    /// matches start at the same place the digit wins, then the word that
+    /// comes first.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Result<Self, Error> {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let mut patterns: Vec<&str> = digits.to_vec();
+        let mut values: Vec<u32> = (0..10).collect();
+        for (word, value) in words {
+            if word.is_empty() {
+                return Err(Error::parse(Day01::DAY, "palabra vacia en el diccionario"));
+            }
+            if value > 9 {
+                return Err(Error::parse(
+                    Day01::DAY,
+                    format!("{} vale {}, que no es un digito", word, value),
+                ));
+            }
+            patterns.push(word);
+            values.push(value);
+        }
+        let automaton =
+            AhoCorasick::new(&patterns).map_err(|e| Error::parse(Day01::DAY, e.to_string()))?;
+        Ok(Dictionary { automaton, values })
+    }
+
+    /// Only `0` to `9`.
+    pub fn digits() -> Self {
+        Self::new([]).expect("los digitos son un diccionario valido")
+    }
+
+    /// `one` to `nine` besides the digits.
+    pub fn english() -> Self {
+        Self::spelled(ENGLISH)
+    }
+
+    /// `uno` to `nueve` besides the digits.
+    pub fn spanish() -> Self {
+        Self::spelled(SPANISH)
+    }
+
+    fn spelled(words: [&str; 9]) -> Self {
+        let words = words.into_iter().zip(1..);
+        Self::new(words).expect("los numeros escritos son un diccionario valido")
+    }
+
+    /// Where the first and last digit of `line` come from, found in one
+    /// pass. Matches may overlap: `eightwo` is an 8 and a 2.
+    pub fn explain<'a>(&self, line: &'a str) -> Option<Explanation<'a>> {
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
+        let key = |found: &Match| (found.start(), found.pattern());
+        for found in self.automaton.find_overlapping_iter(line) {
+            if first.is_none_or(|first| key(&found) < key(&first)) {
+                first = Some(found);
+            }
+            if last.is_none_or(|last| {
+                found.start() > last.start()
+                    || (found.start() == last.start() && found.pattern() < last.pattern())
+            }) {
+                last = Some(found);
+            }
+        }
+        let source = |found: Match| Source {
+            text: &line[found.range()],
+            column: line[..found.start()].chars().count() + 1,
+            digit: self.values[found.pattern().as_usize()],
+        };
+        Some(Explanation {
+            first: source(first?),
+            last: source(last?),
+        })
+    }
+
+    /// The first and last digit of `line` as a two digit number, `None` if
+    /// it has no digits.
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        self.explain(line).map(|explanation| explanation.value())
+    }
+
+    /// Sum of the calibration values of every line, an error pointing at
+    /// the first line without digits.
+    pub fn calibration_sum(&self, lines: &[&str]) -> Result<u32, Error> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(n, line)| {
+                self.calibration_value(line)
+                    .ok_or_else(|| Error::solve(Day01::DAY, "deveria ser un numero").at_line(n + 1))
+            })
+            .sum()
+    }
+}
+
+/// The text a digit was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Source<'a> {
+    /// The digit or the word as it is in the line.
+    pub text: &'a str,
+    /// Column of its first character, from 1.
+    pub column: usize,
+    /// The digit it stands for.
+    pub digit: u32,
+}
+
+/// The first and last digit of a line, they may be the same match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// Where the tens come from.
+    pub first: Source<'a>,
+    /// Where the units come from.
+    pub last: Source<'a>,
+}
+
+impl Explanation<'_> {
+    /// The calibration value of the line.
+    pub fn value(&self) -> u32 {
+        self.first.digit * 10 + self.last.digit
+    }
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Explanation { first, last } = self;
+        write!(
+            f,
+            "{:?} en la columna {} -> {}, {:?} en la columna {} -> {}: {}",
+            first.text,
+            first.column,
+            first.digit,
+            last.text,
+            last.column,
+            last.digit,
+            self.value()
+        )
+    }
+}
+
+/// The [`Solution`] for day 1, the input is the calibration document line by
+/// line.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        parse::lines_with(Self::DAY, input, Ok)
+    }
+
+    fn part1(lines: &Vec<&str>) -> Result<u32, Error> {
+        Dictionary::digits().calibration_sum(lines)
+    }
+
+    fn part2(lines: &Vec<&str>) -> Result<u32, Error> {
+        Dictionary::english().calibration_sum(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let lines = Day01::parse(include_str!("test_input1.txt")).unwrap();
+        assert_eq!(Day01::part1(&lines), Ok(142));
+    }
+
+    #[test]
+    fn test_part2() {
+        let lines = Day01::parse(include_str!("test_input2.txt")).unwrap();
+        assert_eq!(Day01::part2(&lines), Ok(281));
+    }
+
+    #[test]
+    fn test_calibration_value() {
+        let (digits, english) = (Dictionary::digits(), Dictionary::english());
+        assert_eq!(digits.calibration_value("treb7uchet"), Some(77));
+        assert_eq!(digits.calibration_value("xtwone3four"), Some(33));
+        assert_eq!(english.calibration_value("xtwone3four"), Some(24));
+        assert_eq!(english.calibration_value("eightwo"), Some(82));
+        assert_eq!(english.calibration_value("oneight"), Some(18));
+        assert_eq!(english.calibration_value("pqrstu"), None);
+        assert_eq!(digits.calibration_value("a0b"), Some(0));
+    }
+
+    #[test]
+    fn test_diccionarios() {
+        let spanish = Dictionary::spanish();
+        assert_eq!(spanish.calibration_value("xdosiete3cuatro"), Some(24));
+        assert_eq!(spanish.calibration_value("nueveocho"), Some(98));
+        assert_eq!(spanish.calibration_value("one two"), None);
+
+        let custom = Dictionary::new([("zero", 0), ("one", 1), ("x", 9)]).unwrap();
+        assert_eq!(custom.calibration_value("zero3"), Some(3));
+        assert_eq!(custom.calibration_value("zerone"), Some(1));
+        assert_eq!(custom.calibration_value("onezero"), Some(10));
+        assert_eq!(custom.calibration_value("x"), Some(99));
+
+        assert!(Dictionary::new([("", 1)]).is_err());
+        assert!(Dictionary::new([("diez", 10)]).is_err());
+    }
+
+    #[test]
+    fn test_sin_digitos() {
+        let lines = Day01::parse("1abc2\npqrstu").unwrap();
+        let error = Day01::part1(&lines).unwrap_err();
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day01>(include_str!("test_input1.txt"));
+    }
+}
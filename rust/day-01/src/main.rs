@@ -0,0 +1,107 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc_core::{Error, Solution};
+use clap::{Parser, ValueEnum};
+use day_01::{Day01, Dictionary, Mode};
+
+/// Sums the calibration values of a document read line by line. Without
+/// arguments solves both parts on the puzzle input.
+#[derive(Parser)]
+struct Args {
+    /// Document to read, `-` reads stdin. Defaults to the puzzle input.
+    input: Option<PathBuf>,
+    /// Words that count as digits.
+    #[arg(short, long, value_enum)]
+    words: Option<Words>,
+    /// Skip lines without a calibration value instead of failing, count
+    /// them and list the first ones.
+    #[arg(short, long)]
+    lenient: bool,
+    /// Show which characters gave the first and last digit of each line.
+    #[arg(short, long)]
+    explain: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Words {
+    /// Only `0` to `9`, like part 1.
+    Digits,
+    /// `one` to `nine`, like part 2.
+    English,
+    /// `uno` to `nueve`.
+    Spanish,
+}
+
+fn solve() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let lines = Day01::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day01::part1(&lines)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day01::part2(&lines)?,
+        now.elapsed()
+    );
+    Ok(())
+}
+
+fn stream(args: &Args) -> Result<(), Error> {
+    let dictionary = match args.words.unwrap_or(Words::English) {
+        Words::Digits => Dictionary::digits(),
+        Words::English => Dictionary::english(),
+        Words::Spanish => Dictionary::spanish(),
+    };
+    let mode = if args.lenient {
+        Mode::Lenient
+    } else {
+        Mode::Strict
+    };
+    let reader: Box<dyn BufRead> = match &args.input {
+        None => Box::new(include_str!("input.txt").as_bytes()),
+        Some(path) if path.as_os_str() == "-" => Box::new(io::stdin().lock()),
+        Some(path) => {
+            let file = File::open(path).map_err(|e| {
+                let message = format!("no se pudo leer {}: {}", path.display(), e);
+                Error::read(Day01::DAY, message)
+            })?;
+            Box::new(BufReader::new(file))
+        }
+    };
+    let report = dictionary.read_explained(reader, mode, |n, explanation| {
+        if args.explain {
+            println!("{}: {}", n, explanation);
+        }
+    })?;
+    for skipped in &report.samples {
+        eprintln!("linea {} salteada: {}", skipped.line, skipped.reason);
+    }
+    if report.skipped > report.samples.len() {
+        let more = report.skipped - report.samples.len();
+        eprintln!("y {} lineas salteadas mas", more);
+    }
+    println!("suma: {} ({} lineas)", report.sum, report.lines);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let streamed = args.input.is_some() || args.words.is_some() || args.lenient || args.explain;
+    let result = if streamed { stream(&args) } else { solve() };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
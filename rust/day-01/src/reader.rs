@@ -0,0 +1,230 @@
+use std::io::BufRead;
+
+use aoc_core::{Error, Solution};
+
+use crate::{Day01, Dictionary, Explanation};
+
+/// What to do with a line that has no calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Fail on the first bad line.
+    #[default]
+    Strict,
+    /// Leave bad lines out of the sum, count them and keep the first
+    /// [`SAMPLES`].
+    Lenient,
+}
+
+/// How many of the lines [`Mode::Lenient`] leaves out a [`Report`] keeps,
+/// so a document of bad lines doesn't fill the memory.
+pub const SAMPLES: usize = 20;
+
+/// A line [`Mode::Lenient`] left out of the sum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Skipped {
+    /// Number of the line, from 1.
+    pub line: usize,
+    /// Why it has no calibration value.
+    pub reason: String,
+}
+
+/// What reading a whole document gave.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Report {
+    /// Sum of the calibration values.
+    pub sum: u64,
+    /// Lines that added to the sum.
+    pub lines: usize,
+    /// How many lines were left out, only in [`Mode::Lenient`].
+    pub skipped: usize,
+    /// The first [`SAMPLES`] lines left out.
+    pub samples: Vec<Skipped>,
+}
+
+impl Dictionary {
+    /// Sums the calibration values of `reader` one line at a time, holding
+    /// only the current line in memory. Blank lines at the end are ignored
+    /// like in [`Day01::parse`].
+    pub fn read(&self, reader: impl BufRead, mode: Mode) -> Result<Report, Error> {
+        self.read_explained(reader, mode, |_, _| {})
+    }
+
+    /// Like [`Dictionary::read`], calling `explain` with the number and the
+    /// [`Explanation`] of every line that adds to the sum.
+    pub fn read_explained(
+        &self,
+        mut reader: impl BufRead,
+        mode: Mode,
+        mut explain: impl FnMut(usize, &Explanation),
+    ) -> Result<Report, Error> {
+        let mut report = Report::default();
+        let mut buffer = Vec::new();
+        // blank lines are only bad if something comes after them, the first
+        // line of the current run of them and how many there are
+        let (mut blank, mut blanks) = (0, 0);
+        let bad = |report: &mut Report, line: usize, error: Error| match mode {
+            Mode::Strict => Err(error.at_line(line)),
+            Mode::Lenient => {
+                report.skipped += 1;
+                if report.samples.len() < SAMPLES {
+                    report.samples.push(Skipped {
+                        line,
+                        reason: error.message,
+                    });
+                }
+                Ok(())
+            }
+        };
+        for n in 1.. {
+            buffer.clear();
+            let read = reader
+                .read_until(b'\n', &mut buffer)
+                .map_err(|e| Error::read(Day01::DAY, e.to_string()).at_line(n))?;
+            if read == 0 {
+                break;
+            }
+            let line = match std::str::from_utf8(&buffer) {
+                Ok(line) => line.trim_end_matches(['\r', '\n']),
+                Err(e) => {
+                    let error = Error::read(Day01::DAY, format!("no es UTF-8: {}", e));
+                    bad(&mut report, n, error)?;
+                    continue;
+                }
+            };
+            if line.is_empty() {
+                if blanks == 0 {
+                    blank = n;
+                }
+                blanks += 1;
+                continue;
+            }
+            for line in blank..blank + std::mem::take(&mut blanks) {
+                bad(&mut report, line, Error::solve(Day01::DAY, "linea vacia"))?;
+            }
+            match self.explain(line) {
+                Some(explanation) => {
+                    explain(n, &explanation);
+                    report.sum += u64::from(explanation.value());
+                    report.lines += 1;
+                }
+                None => bad(
+                    &mut report,
+                    n,
+                    Error::solve(Day01::DAY, "deveria ser un numero"),
+                )?,
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        let english = Dictionary::english();
+        let report = english
+            .read(include_bytes!("test_input2.txt").as_slice(), Mode::Strict)
+            .unwrap();
+        assert_eq!((report.sum, report.lines, report.skipped), (281, 7, 0));
+        assert!(report.samples.is_empty());
+
+        let report = english.read("1abc2\r\nonetwo\r\n\r\n\n".as_bytes(), Mode::Strict);
+        assert_eq!(report.unwrap().sum, 12 + 12);
+    }
+
+    #[test]
+    fn test_modes() {
+        let input: &[u8] = b"1abc2\npqrstu\n\nxone\n\xff9\n";
+        let digits = Dictionary::digits();
+        let error = digits.read(input, Mode::Strict).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (Some(2), "deveria ser un numero")
+        );
+
+        let report = digits.read(input, Mode::Lenient).unwrap();
+        assert_eq!((report.sum, report.lines), (12, 1));
+        let lines: Vec<usize> = report.samples.iter().map(|s| s.line).collect();
+        assert_eq!(lines, [2, 3, 4, 5]);
+        assert_eq!(report.skipped, 4);
+        assert_eq!(report.samples[1].reason, "linea vacia");
+        assert!(report.samples[3].reason.starts_with("no es UTF-8"));
+
+        let report = Dictionary::english().read(input, Mode::Lenient);
+        assert_eq!(report.unwrap().sum, 12 + 11);
+    }
+
+    #[test]
+    fn test_blank_runs() {
+        let digits = Dictionary::digits();
+        let input = "12\n\n\n34\n\n56";
+        let error = digits.read(input.as_bytes(), Mode::Strict).unwrap_err();
+        assert_eq!(error.line, Some(2));
+        let report = digits.read(input.as_bytes(), Mode::Lenient).unwrap();
+        let lines: Vec<usize> = report.samples.iter().map(|s| s.line).collect();
+        assert_eq!(lines, [2, 3, 5]);
+
+        let input = format!("12{}", "\n".repeat(100_000));
+        let report = digits.read(input.as_bytes(), Mode::Strict).unwrap();
+        assert_eq!((report.sum, report.lines), (12, 1));
+    }
+
+    #[test]
+    fn test_samples() {
+        let input = format!("{}34\n", "x\n".repeat(100_000));
+        let report = Dictionary::digits().read(input.as_bytes(), Mode::Lenient);
+        let report = report.unwrap();
+        assert_eq!((report.sum, report.lines, report.skipped), (34, 1, 100_000));
+        let lines: Vec<usize> = report.samples.iter().map(|s| s.line).collect();
+        assert_eq!(lines, Vec::from_iter(1..=SAMPLES));
+    }
+
+    #[test]
+    fn test_read_explained() {
+        let mut explained = Vec::new();
+        let report = Dictionary::english()
+            .read_explained("eightwo\n7pqrst".as_bytes(), Mode::Strict, |n, e| {
+                explained.push((n, e.to_string()))
+            })
+            .unwrap();
+        assert_eq!(report.sum, 82 + 77);
+        assert_eq!(
+            explained,
+            [
+                (
+                    1,
+                    "\"eight\" en la columna 1 -> 8, \"two\" en la columna 5 -> 2: 82".to_string()
+                ),
+                (
+                    2,
+                    "\"7\" en la columna 1 -> 7, \"7\" en la columna 1 -> 7: 77".to_string()
+                ),
+            ]
+        );
+    }
+
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disco roto"))
+        }
+    }
+
+    #[test]
+    fn test_read_error() {
+        let reader = "12\n".as_bytes().chain(BufReader::new(Broken));
+        let error = Dictionary::digits()
+            .read(reader, Mode::Lenient)
+            .unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (Some(2), "disco roto")
+        );
+    }
+}
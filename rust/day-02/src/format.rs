@@ -0,0 +1,310 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use aoc_core::{parse, Error, Solution};
+
+use crate::{Day02, Game, Set, Tirada};
+
+const CSV_HEADER: &str = "game,set,count,color";
+
+/// The formats a game log can be written in and read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// The puzzle's own, `Game 1: 3 blue, 4 red; 1 red`.
+    #[default]
+    Text,
+    /// An array of games, each with its `id` and its `sets` as arrays of
+    /// `{"count": 3, "color": "blue"}`.
+    Json,
+    /// A `game,set,count,color` header and a row for every draw. The rows of
+    /// a game go together and its sets are numbered from 1 in order.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "formato desconocido {}, puede ser text, json o csv",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Writes `games` in `format`, every line ends with a line break.
+pub fn write(games: &[Game], format: Format) -> String {
+    match format {
+        Format::Text => games.iter().map(|game| format!("{}\n", game)).collect(),
+        Format::Json => {
+            let json = serde_json::to_string_pretty(games).expect("los juegos son datos planos");
+            json + "\n"
+        }
+        Format::Csv => {
+            let mut csv = format!("{}\n", CSV_HEADER);
+            for game in games {
+                for (n, set) in game.sets.iter().enumerate() {
+                    for tirada in &set.tiradas {
+                        let row = format!(
+                            "{},{},{},{}\n",
+                            game.id,
+                            n + 1,
+                            tirada.cantidad,
+                            tirada.color
+                        );
+                        csv.push_str(&row);
+                    }
+                }
+            }
+            csv
+        }
+    }
+}
+
+/// Reads games written in `format`. Whatever the format, ids can't repeat,
+/// games need a set, sets need a draw and colours are letters, so anything
+/// read can be written as text.
+pub fn read(input: &str, format: Format) -> Result<Vec<Game<'_>>, Error> {
+    match format {
+        Format::Text => Day02::parse(input),
+        Format::Json => read_json(input),
+        Format::Csv => read_csv(input),
+    }
+}
+
+fn read_json(input: &str) -> Result<Vec<Game<'_>>, Error> {
+    let games: Vec<Game> = serde_json::from_str(input)
+        .map_err(|e| Error::parse(Day02::DAY, e.to_string()).at(e.line(), e.column()))?;
+    for game in &games {
+        let error = |message: String| Err(Error::parse(Day02::DAY, message));
+        if game.sets.is_empty() {
+            return error(format!("el juego {} no tiene sets", game.id));
+        }
+        if game.sets.iter().any(|set| set.tiradas.is_empty()) {
+            return error(format!("el juego {} tiene un set vacio", game.id));
+        }
+        let mut tiradas = game.sets.iter().flat_map(|set| &set.tiradas);
+        if let Some(tirada) = tiradas.find(|tirada| !is_color(&tirada.color)) {
+            return error(format!("{:?} no es un color", tirada.color));
+        }
+    }
+    check_ids(&games).map_err(|(_, error)| error)?;
+    Ok(games)
+}
+
+// the same rule for every format, the error comes with the index of the
+// game that repeats an earlier id
+pub(crate) fn check_ids(games: &[Game]) -> Result<(), (usize, Error)> {
+    let mut ids = HashSet::new();
+    match games.iter().position(|game| !ids.insert(game.id)) {
+        Some(n) => Err((n, repeated(games[n].id))),
+        None => Ok(()),
+    }
+}
+
+fn repeated(id: u32) -> Error {
+    Error::parse(Day02::DAY, format!("el juego {} ya aparecio antes", id))
+}
+
+fn is_color(color: &str) -> bool {
+    !color.is_empty() && color.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+// 1,2,3,blue
+fn row(line: &str) -> Result<(u32, usize, Tirada<'_>), String> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let [game, set, cantidad, color] = fields[..] else {
+        return Err(format!("se esperaban 4 campos y hay {}", fields.len()));
+    };
+    let number = |field: &str, name: &str| {
+        field
+            .parse::<u32>()
+            .map_err(|_| format!("{} no es un numero de {}", field, name))
+    };
+    let set = number(set, "set")? as usize;
+    if set == 0 {
+        return Err("los sets se numeran desde 1".to_string());
+    }
+    if !is_color(color) {
+        return Err(format!("{:?} no es un color", color));
+    }
+    let tirada = Tirada {
+        color: color.into(),
+        cantidad: number(cantidad, "cubos")?,
+    };
+    Ok((number(game, "juego")?, set, tirada))
+}
+
+fn read_csv(input: &str) -> Result<Vec<Game<'_>>, Error> {
+    let mut header = true;
+    let rows = parse::lines_with(Day02::DAY, input, |line| {
+        if std::mem::take(&mut header) {
+            if line != CSV_HEADER {
+                return Err(format!("se esperaba el encabezado {}", CSV_HEADER));
+            }
+            return Ok(None);
+        }
+        row(line).map(|row| Some((line, row)))
+    })?;
+
+    // a game's rows go together and its sets are numbered in order, a new
+    // set when the number goes up by one
+    let mut games: Vec<Game> = Vec::new();
+    let mut ids = HashSet::new();
+    let mut last_set = 0;
+    for (n, row) in rows.into_iter().enumerate() {
+        let Some((line, (id, set, tirada))) = row else {
+            continue;
+        };
+        let error = |column, message: String| Error::parse(Day02::DAY, message).at(n + 1, column);
+        let set_column = line.find(',').map_or(1, |i| i + 2);
+        match games.last_mut() {
+            Some(game) if game.id == id => {
+                if set == last_set + 1 {
+                    game.sets.push(Set::default());
+                } else if set != last_set {
+                    let message = format!("se esperaba el set {} o el {}", last_set, last_set + 1);
+                    return Err(error(set_column, message));
+                }
+            }
+            _ => {
+                if !ids.insert(id) {
+                    return Err(repeated(id).at(n + 1, 1));
+                }
+                if set != 1 {
+                    let message = format!("el juego {} tiene que empezar por el set 1", id);
+                    return Err(error(set_column, message));
+                }
+                games.push(Game {
+                    id,
+                    sets: vec![Set::default()],
+                });
+            }
+        }
+        last_set = set;
+        let game = games.last_mut().expect("recien agregado");
+        let set = game.sets.last_mut().expect("recien agregado");
+        set.tiradas.push(tirada);
+    }
+    Ok(games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [Format; 3] = [Format::Text, Format::Json, Format::Csv];
+
+    #[test]
+    fn test_write() {
+        let games = Day02::parse("Game 1: 3 blue, 4 red; 2 green\nGame 7: 1 red").unwrap();
+        assert_eq!(
+            write(&games, Format::Csv),
+            "game,set,count,color\n1,1,3,blue\n1,1,4,red\n1,2,2,green\n7,1,1,red\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&write(&games, Format::Json)).unwrap();
+        assert_eq!(json[0]["sets"][1][0]["color"], "green");
+        assert_eq!(json[1]["id"], 7);
+        assert_eq!(json[1]["sets"][0][0]["count"], 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = include_str!("test_input1.txt");
+        let games = Day02::parse(text).unwrap();
+        for format in FORMATS {
+            let written = write(&games, format);
+            let parsed = read(&written, format).unwrap();
+            assert_eq!(write(&parsed, Format::Text), write(&games, Format::Text));
+            for other in FORMATS {
+                let again = write(&parsed, other);
+                assert_eq!(write(&read(&again, other).unwrap(), format), written);
+            }
+        }
+        assert_eq!(Day02::part1(&read(text, Format::Text).unwrap()), Ok(8));
+    }
+
+    #[test]
+    fn test_repeated_ids() {
+        // no format reads them, so converting never merges two games
+        let error = read("Game 1: 1 red\nGame 1: 2 blue", Format::Text).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+        assert_eq!(error.message, "el juego 1 ya aparecio antes");
+
+        // built by hand, with a game in between so the CSV rows of the
+        // repeated id don't go together
+        let games = Day02::parse("Game 1: 1 red\nGame 2: 3 green\nGame 3: 2 blue").unwrap();
+        let games: Vec<Game> = games
+            .into_iter()
+            .map(|game| match game.id {
+                3 => Game { id: 1, ..game },
+                _ => game,
+            })
+            .collect();
+        for format in FORMATS {
+            let error = read(&write(&games, format), format).unwrap_err();
+            assert_eq!(error.message, "el juego 1 ya aparecio antes");
+        }
+    }
+
+    #[test]
+    fn test_json_escapes() {
+        let json = r#"[{"id": 1, "sets": [[{"count": 3, "color": "r\u0065d"}]]}]"#;
+        let games = read(json, Format::Json).unwrap();
+        assert_eq!(write(&games, Format::Text), "Game 1: 3 red\n");
+        let json = r#"[{"id": 1, "sets": [[{"count": 3, "color": "r\u0020d"}]]}]"#;
+        assert!(read(json, Format::Json).is_err());
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = read("game,set,count,color\n1,1,3,blue\n1,x,3,red", Format::Csv).unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.message, "x no es un numero de set");
+        let error = read("1,1,3,blue", Format::Csv).unwrap_err();
+        assert_eq!(error.line, Some(1));
+        assert!(read("game,set,count,color\n1,1,3,light blue", Format::Csv).is_err());
+        assert!(read("game,set,count,color\n1,0,3,blue", Format::Csv).is_err());
+        let csv = "game,set,count,color\n1,1,3,blue\n2,1,3,red\n1,1,3,red";
+        let error = read(csv, Format::Csv).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(4), Some(1)));
+        let csv = "game,set,count,color\n1,1,3,blue\n1,3,3,red\n1,3,3,red\n1,1,2,blue";
+        let error = read(csv, Format::Csv).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+        let csv = "game,set,count,color\n1,1,3,blue\n1,2,3,red\n1,1,2,blue";
+        let error = read(csv, Format::Csv).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(4), Some(3)));
+        let csv = "game,set,count,color\n1,1,3,blue\n12,2,3,red";
+        let error = read(csv, Format::Csv).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(4)));
+
+        let error = read(
+            "[{\"id\": 1,\n \"sets\": [[{\"count\": -3}]]}]",
+            Format::Json,
+        )
+        .unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert!(read("[{\"id\": 1, \"sets\": []}]", Format::Json).is_err());
+        assert!(read("[{\"id\": 1, \"sets\": [[]]}]", Format::Json).is_err());
+        let json = "[{\"id\": 1, \"sets\": [[{\"count\": 3, \"color\": \"re d\"}]]}]";
+        assert!(read(json, Format::Json).is_err());
+
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,237 @@
+//! Day 2: Cube Conundrum. Games of cubes drawn from a bag, and a
+//! [`Query`] language to select them. Logs can also be read and written as
+//! JSON and CSV, see [`Format`].
+
+#![warn(missing_docs)]
+
+use std::{borrow::Cow, collections::HashMap, fmt};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+use serde::{Deserialize, Serialize};
+
+use aoc_core::{parse, Error, Solution};
+
+/// Game logs as text, JSON and CSV.
+pub mod format;
+/// Questions about the games in a small expression language.
+pub mod query;
+
+pub use format::Format;
+pub use query::Query;
+
+/// How many cubes of each colour a draw shows, `3 blue`. The colour is
+/// owned only when it was read from an escaped JSON string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tirada<'a> {
+    #[serde(rename = "count")]
+    cantidad: u32,
+    #[serde(borrow)]
+    color: Cow<'a, str>,
+}
+
+/// The draws shown together, `3 blue, 4 red`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Set<'a> {
+    #[serde(borrow)]
+    tiradas: Vec<Tirada<'a>>,
+}
+
+/// A game, its id and every set of cubes shown.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Game<'a> {
+    id: u32,
+    #[serde(borrow)]
+    sets: Vec<Set<'a>>,
+}
+
+impl Game<'_> {
+    /// The game's id.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Whether a bag with `bag` cubes of each colour could have given every
+    /// set, an error for colours the bag doesn't have.
+    pub fn is_possible(&self, bag: &HashMap<&str, u32>) -> Result<bool, Error> {
+        let mut tiradas = self.sets.iter().flat_map(|set| set.tiradas.iter());
+        tiradas.try_fold(true, |posible, tirada| {
+            let maximo = bag.get(tirada.color.as_ref()).ok_or_else(|| {
+                Error::solve(Day02::DAY, format!("no existe el color {}", tirada.color))
+            })?;
+            Ok(posible && tirada.cantidad <= *maximo)
+        })
+    }
+
+    /// Fewest cubes of each colour the bag needs for the game.
+    pub fn fewest_cubes(&self) -> HashMap<&str, u32> {
+        let mut fewest = HashMap::new();
+        for tirada in self.sets.iter().flat_map(|set| set.tiradas.iter()) {
+            let cantidad = fewest.entry(tirada.color.as_ref()).or_insert(0);
+            *cantidad = (*cantidad).max(tirada.cantidad);
+        }
+        fewest
+    }
+
+    /// Product of the fewest cubes of each colour the game needs, an error
+    /// if it doesn't fit in a `u64`.
+    pub fn power(&self) -> Result<u64, Error> {
+        let mut cantidades = self.fewest_cubes().into_values().map(u64::from);
+        cantidades
+            .try_fold(1_u64, |power, cantidad| power.checked_mul(cantidad))
+            .ok_or_else(|| {
+                let message = format!("la potencia del juego {} no entra en un u64", self.id);
+                Error::solve(Day02::DAY, message)
+            })
+    }
+}
+
+impl fmt::Display for Tirada<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.cantidad, self.color)
+    }
+}
+
+impl fmt::Display for Set<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tiradas = self
+            .tiradas
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", tiradas.join(", "))
+    }
+}
+
+impl fmt::Display for Game<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets = self.sets.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        write!(f, "Game {}: {}", self.id, sets.join("; "))
+    }
+}
+
+// 3 blue
+fn tirada(input: &str) -> IResult<&str, Tirada<'_>> {
+    let (input, (cantidad, color)) = separated_pair(complete::u32, tag(" "), alpha1)(input)?;
+    let color = Cow::Borrowed(color);
+    Ok((input, Tirada { color, cantidad }))
+}
+// 3 blue, 4 red
+fn set(input: &str) -> IResult<&str, Set<'_>> {
+    let (input, tiradas) = separated_list1(tag(", "), tirada)(input)?;
+    Ok((input, Set { tiradas }))
+}
+//Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn game(input: &str) -> IResult<&str, Game<'_>> {
+    let (input, id) = preceded(tag("Game "), complete::u32)(input)?;
+    let (input, sets) = preceded(tag(": "), separated_list1(tag("; "), set))(input)?;
+    Ok((input, Game { id, sets }))
+}
+
+/// Parses the games and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let games = Day02::parse(input)?;
+    Ok(format::write(&games, Format::Text))
+}
+
+/// The [`Solution`] for day 2, the input is the list of games.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Game<'a>>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    // a game a line, no id twice
+    fn parse(input: &str) -> Result<Vec<Game<'_>>, Error> {
+        let games = parse::lines(Self::DAY, input, game)?;
+        format::check_ids(&games).map_err(|(n, error)| error.at(n + 1, 1))?;
+        Ok(games)
+    }
+
+    // the games possible with 12 red, 13 green and 14 blue cubes
+    fn part1(games: &Vec<Game>) -> Result<u32, Error> {
+        let bag = HashMap::from([("blue", 14), ("red", 12), ("green", 13)]);
+        let mut res = 0;
+        for game in games.iter() {
+            if game.is_possible(&bag)? {
+                res += game.id();
+            }
+        }
+        Ok(res)
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u64, Error> {
+        games.iter().try_fold(0_u64, |res, game| {
+            res.checked_add(game.power()?)
+                .ok_or_else(|| Error::solve(Self::DAY, "la suma no entra en un u64"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<(), Error> {
+        let games = Day02::parse(include_str!("test_input1.txt"))?;
+        assert_eq!(Day02::part1(&games)?, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), Error> {
+        let games = Day02::parse(include_str!("test_input1.txt"))?;
+        assert_eq!(Day02::part2(&games)?, 2286);
+        assert_eq!(games[0].fewest_cubes()["blue"], 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_desborde() -> Result<(), Error> {
+        let games = Day02::parse("Game 1: 100000 red, 100000 blue, 100000 green")?;
+        assert_eq!(Day02::part2(&games)?, 1_000_000_000_000_000);
+        let games = Day02::parse("Game 1: 4000000000 red, 4000000000 blue, 4000000000 green")?;
+        let error = Day02::part2(&games).unwrap_err();
+        assert_eq!(error.message, "la potencia del juego 1 no entra en un u64");
+        let game = "4000000000 red, 4000000000 blue";
+        let input = format!("Game 1: {}\nGame 2: {}", game, game);
+        let games = Day02::parse(&input)?;
+        assert_eq!(games[0].power()?, 16_000_000_000_000_000_000);
+        let error = Day02::part2(&games).unwrap_err();
+        assert_eq!(error.message, "la suma no entra en un u64");
+        Ok(())
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day02::parse("Game 1: 3 blue\nGame 2: 4 red; x").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(14)));
+        let games = Day02::parse("Game 1: 3 purple").unwrap();
+        let error = Day02::part1(&games).unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Solve);
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "Game 01: 3 blue, 04 red\nGame 2: 1 green; 2 red  \n";
+        let written = rewrite(input).unwrap();
+        assert_eq!(written, "Game 1: 3 blue, 4 red\nGame 2: 1 green; 2 red\n");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day02>(include_str!("test_input1.txt"));
+    }
+}
@@ -0,0 +1,100 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_core::{Error, Solution};
+use clap::Parser;
+use day_02::{format, Day02, Format, Query};
+
+/// Solves both parts on the puzzle input, selects games with a query like
+/// `red <= 12 && green + blue <= 20 && sets <= 3` or converts a game log to
+/// another format.
+#[derive(Parser)]
+struct Args {
+    /// Condition the selected games meet.
+    #[arg(short, long, conflicts_with = "to")]
+    query: Option<String>,
+    /// Format of the game log read: text, json or csv.
+    #[arg(short, long, default_value_t = Format::Text)]
+    from: Format,
+    /// Write the game log in this format: text, json or csv.
+    #[arg(short, long)]
+    to: Option<Format>,
+    /// Game log to read, `-` reads stdin. Defaults to the puzzle input.
+    input: Option<PathBuf>,
+}
+
+fn solve() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let games = Day02::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day02::part1(&games)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day02::part2(&games)?,
+        now.elapsed()
+    );
+    Ok(())
+}
+
+fn read(path: Option<&PathBuf>) -> Result<String, Error> {
+    match path {
+        Some(path) => aoc_core::input::read(path).map_err(|e| {
+            let message = format!("no se pudo leer {}: {}", path.display(), e);
+            Error::read(Day02::DAY, message)
+        }),
+        None => Ok(include_str!("input.txt").to_string()),
+    }
+}
+
+fn query(query: &str, args: &Args) -> Result<(), Error> {
+    let query = Query::new(query)?;
+    let input = read(args.input.as_ref())?;
+    let games = format::read(&input, args.from)?;
+    let selection = query.select(&games)?;
+    let ids: Vec<String> = selection.ids.iter().map(u32::to_string).collect();
+    println!("{} juegos: {}", ids.len(), ids.join(", "));
+    for (color, stats) in &selection.colors {
+        println!(
+            "{}: {} juegos, min {}, max {}, total {}, promedio {:.2}",
+            color,
+            stats.games,
+            stats.min,
+            stats.max,
+            stats.total,
+            stats.mean()
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let result = match (&args.query, args.to) {
+        (Some(expression), _) => query(expression, &args),
+        (None, Some(to)) => read(args.input.as_ref()).and_then(|input| {
+            let games = format::read(&input, args.from)?;
+            print!("{}", format::write(&games, to));
+            Ok(())
+        }),
+        (None, None) if args.input.is_some() || args.from != Format::Text => {
+            read(args.input.as_ref()).and_then(|input| {
+                let games = format::read(&input, args.from)?;
+                println!("solutions part 1: {}", Day02::part1(&games)?);
+                println!("solutions part 2: {}", Day02::part2(&games)?);
+                Ok(())
+            })
+        }
+        (None, None) => solve(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::{BTreeMap, HashSet};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, multispace0},
+    combinator::{cut, map, value},
+    multi::fold_many0,
+    sequence::{delimited, pair, preceded, tuple},
+    IResult,
+};
+
+use aoc_core::{parse, Error, Solution};
+
+use crate::{Day02, Game};
+
+/// A number worked out from a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    /// A literal, `12`.
+    Number(i64),
+    /// Fewest cubes of a colour the game needs, `red`. Zero for a colour
+    /// the game never shows.
+    Color(String),
+    /// How many sets the game shows, `sets`.
+    Sets,
+    /// The game's id, `id`.
+    Id,
+    /// `a + b`
+    Add(Box<Expression>, Box<Expression>),
+    /// `a - b`
+    Sub(Box<Expression>, Box<Expression>),
+    /// `a * b`
+    Mul(Box<Expression>, Box<Expression>),
+}
+
+/// How two numbers are compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+}
+
+/// Whether a game is selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// `a <= b`
+    Compare(Expression, Comparison, Expression),
+    /// `a && b`
+    And(Box<Condition>, Box<Condition>),
+    /// `a || b`
+    Or(Box<Condition>, Box<Condition>),
+    /// `!a`
+    Not(Box<Condition>),
+}
+
+impl Expression {
+    /// The value for `game`.
+    pub fn evaluate(&self, game: &Game) -> i64 {
+        match self {
+            Expression::Number(n) => *n,
+            Expression::Color(color) => game
+                .fewest_cubes()
+                .get(color.as_str())
+                .map_or(0, |&n| i64::from(n)),
+            Expression::Sets => game.sets.len() as i64,
+            Expression::Id => i64::from(game.id),
+            Expression::Add(a, b) => a.evaluate(game).saturating_add(b.evaluate(game)),
+            Expression::Sub(a, b) => a.evaluate(game).saturating_sub(b.evaluate(game)),
+            Expression::Mul(a, b) => a.evaluate(game).saturating_mul(b.evaluate(game)),
+        }
+    }
+
+    fn colors<'a>(&'a self, colors: &mut Vec<&'a str>) {
+        match self {
+            Expression::Color(color) => colors.push(color),
+            Expression::Add(a, b) | Expression::Sub(a, b) | Expression::Mul(a, b) => {
+                a.colors(colors);
+                b.colors(colors);
+            }
+            Expression::Number(_) | Expression::Sets | Expression::Id => {}
+        }
+    }
+}
+
+impl Comparison {
+    fn holds(self, a: i64, b: i64) -> bool {
+        match self {
+            Comparison::Less => a < b,
+            Comparison::LessOrEqual => a <= b,
+            Comparison::Greater => a > b,
+            Comparison::GreaterOrEqual => a >= b,
+            Comparison::Equal => a == b,
+            Comparison::NotEqual => a != b,
+        }
+    }
+}
+
+impl Condition {
+    /// Whether `game` meets the condition.
+    pub fn matches(&self, game: &Game) -> bool {
+        match self {
+            Condition::Compare(a, comparison, b) => {
+                comparison.holds(a.evaluate(game), b.evaluate(game))
+            }
+            Condition::And(a, b) => a.matches(game) && b.matches(game),
+            Condition::Or(a, b) => a.matches(game) || b.matches(game),
+            Condition::Not(a) => !a.matches(game),
+        }
+    }
+
+    fn colors<'a>(&'a self, colors: &mut Vec<&'a str>) {
+        match self {
+            Condition::Compare(a, _, b) => {
+                a.colors(colors);
+                b.colors(colors);
+            }
+            Condition::And(a, b) | Condition::Or(a, b) => {
+                a.colors(colors);
+                b.colors(colors);
+            }
+            Condition::Not(a) => a.colors(colors),
+        }
+    }
+}
+
+/// The fewest cubes of a colour the selected games need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorStats {
+    /// Selected games that show the colour.
+    pub games: usize,
+    /// The least any of them needs.
+    pub min: u32,
+    /// The most any of them needs.
+    pub max: u32,
+    /// What they need all together.
+    pub total: u64,
+}
+
+impl ColorStats {
+    /// What a game that shows the colour needs on average.
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.games as f64
+    }
+}
+
+/// The games a [`Query`] selected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection {
+    /// Their ids, in the order of the games.
+    pub ids: Vec<u32>,
+    /// Statistics of every colour they show.
+    pub colors: BTreeMap<String, ColorStats>,
+}
+
+/// A condition over the games, `red <= 12 && green + blue <= 20 && sets <= 3`.
+///
+/// Colours stand for the fewest cubes of that colour the game needs, `sets`
+/// for how many sets it shows and `id` for its id. Numbers can be added,
+/// subtracted and multiplied and compared with `<`, `<=`, `>`, `>=`, `==`
+/// and `!=`, comparisons joined with `&&`, `||` and `!`, all of it grouped
+/// with parentheses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    condition: Condition,
+}
+
+impl Query {
+    /// Parses a query, an error points at where it stops making sense.
+    pub fn new(input: &str) -> Result<Query, Error> {
+        let condition = parse::all(Day02::DAY, input, or)?;
+        Ok(Query { condition })
+    }
+
+    /// The parsed condition.
+    pub fn condition(&self) -> &Condition {
+        &self.condition
+    }
+
+    /// Whether `game` is selected.
+    pub fn matches(&self, game: &Game) -> bool {
+        self.condition.matches(game)
+    }
+
+    /// The games selected from `games`, an error for colours none of them
+    /// shows, which are most likely a typo.
+    pub fn select(&self, games: &[Game]) -> Result<Selection, Error> {
+        let shown: HashSet<&str> = games
+            .iter()
+            .flat_map(|game| game.sets.iter().flat_map(|set| &set.tiradas))
+            .map(|tirada| tirada.color.as_ref())
+            .collect();
+        let mut colors = Vec::new();
+        self.condition.colors(&mut colors);
+        if let Some(color) = colors.iter().find(|color| !shown.contains(*color)) {
+            let message = format!("no existe el color {}", color);
+            return Err(Error::solve(Day02::DAY, message));
+        }
+
+        let mut selection = Selection {
+            ids: Vec::new(),
+            colors: BTreeMap::new(),
+        };
+        for game in games.iter().filter(|game| self.matches(game)) {
+            selection.ids.push(game.id);
+            for (color, cantidad) in game.fewest_cubes() {
+                let stats = selection
+                    .colors
+                    .entry(color.to_string())
+                    .or_insert(ColorStats {
+                        games: 0,
+                        min: cantidad,
+                        max: cantidad,
+                        total: 0,
+                    });
+                stats.games += 1;
+                stats.min = stats.min.min(cantidad);
+                stats.max = stats.max.max(cantidad);
+                stats.total += u64::from(cantidad);
+            }
+        }
+        Ok(selection)
+    }
+}
+
+fn ws<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    delimited(multispace0, parser, multispace0)
+}
+
+// 12, red, sets, (red + blue)
+fn factor(input: &str) -> IResult<&str, Expression> {
+    ws(alt((
+        map(complete::i64, Expression::Number),
+        map(alpha1, |name| match name {
+            "sets" => Expression::Sets,
+            "id" => Expression::Id,
+            color => Expression::Color(color.to_string()),
+        }),
+        delimited(tag("("), sum, tag(")")),
+    )))(input)
+}
+
+// red * 2
+fn term(input: &str) -> IResult<&str, Expression> {
+    let (input, first) = factor(input)?;
+    fold_many0(
+        preceded(tag("*"), factor),
+        move || first.clone(),
+        |a, b| Expression::Mul(Box::new(a), Box::new(b)),
+    )(input)
+}
+
+// green + blue - 1
+fn sum(input: &str) -> IResult<&str, Expression> {
+    let (input, first) = term(input)?;
+    fold_many0(
+        pair(alt((tag("+"), tag("-"))), term),
+        move || first.clone(),
+        |a, (op, b)| match op {
+            "+" => Expression::Add(Box::new(a), Box::new(b)),
+            _ => Expression::Sub(Box::new(a), Box::new(b)),
+        },
+    )(input)
+}
+
+fn comparison(input: &str) -> IResult<&str, Comparison> {
+    alt((
+        value(Comparison::LessOrEqual, tag("<=")),
+        value(Comparison::GreaterOrEqual, tag(">=")),
+        value(Comparison::Equal, tag("==")),
+        value(Comparison::NotEqual, tag("!=")),
+        value(Comparison::Less, tag("<")),
+        value(Comparison::Greater, tag(">")),
+    ))(input)
+}
+
+// !(...), (...), red <= 12
+fn not(input: &str) -> IResult<&str, Condition> {
+    ws(alt((
+        map(preceded(tag("!"), not), |a| Condition::Not(Box::new(a))),
+        map(tuple((sum, comparison, cut(sum))), |(a, comparison, b)| {
+            Condition::Compare(a, comparison, b)
+        }),
+        delimited(tag("("), or, cut(tag(")"))),
+    )))(input)
+}
+
+// red <= 12 && sets <= 3
+fn and(input: &str) -> IResult<&str, Condition> {
+    let (input, first) = not(input)?;
+    fold_many0(
+        preceded(tag("&&"), cut(not)),
+        move || first.clone(),
+        |a, b| Condition::And(Box::new(a), Box::new(b)),
+    )(input)
+}
+
+// red <= 12 || blue > 3
+fn or(input: &str) -> IResult<&str, Condition> {
+    let (input, first) = and(input)?;
+    fold_many0(
+        preceded(tag("||"), cut(and)),
+        move || first.clone(),
+        |a, b| Condition::Or(Box::new(a), Box::new(b)),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn games() -> Vec<Game<'static>> {
+        Day02::parse(include_str!("test_input1.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let query = Query::new("red <= 12 && green + blue <= 20 && sets <= 3").unwrap();
+        let compare = |a, comparison, b| Condition::Compare(a, comparison, b);
+        let color = |c: &str| Expression::Color(c.to_string());
+        let expected = Condition::And(
+            Box::new(Condition::And(
+                Box::new(compare(
+                    color("red"),
+                    Comparison::LessOrEqual,
+                    Expression::Number(12),
+                )),
+                Box::new(compare(
+                    Expression::Add(Box::new(color("green")), Box::new(color("blue"))),
+                    Comparison::LessOrEqual,
+                    Expression::Number(20),
+                )),
+            )),
+            Box::new(compare(
+                Expression::Sets,
+                Comparison::LessOrEqual,
+                Expression::Number(3),
+            )),
+        );
+        assert_eq!(query.condition(), &expected);
+
+        let query = Query::new("(red + 1) * 2 > 4||!(id==3)").unwrap();
+        assert!(matches!(query.condition(), Condition::Or(..)));
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Query::new("red <= 12 && blue >").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(20)));
+        let error = Query::new("red <= 12 &&").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(13)));
+        let error = Query::new("(red <= 12").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(11)));
+        let error = Query::new("red").unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Parse);
+
+        let query = Query::new("gren <= 3").unwrap();
+        let error = query.select(&games()).unwrap_err();
+        assert_eq!(error.message, "no existe el color gren");
+    }
+
+    #[test]
+    fn test_select() {
+        let games = games();
+        let query = Query::new("red <= 12 && green <= 13 && blue <= 14").unwrap();
+        let selection = query.select(&games).unwrap();
+        assert_eq!(selection.ids, [1, 2, 5]);
+        assert_eq!(
+            selection.ids.iter().sum::<u32>(),
+            Day02::part1(&games).unwrap()
+        );
+        let red = selection.colors["red"];
+        assert_eq!((red.games, red.min, red.max, red.total), (3, 1, 6, 11));
+        assert!((red.mean() - 11.0 / 3.0).abs() < 1e-9);
+
+        let query = Query::new("red <= 12 && green + blue <= 20 && sets <= 2").unwrap();
+        assert_eq!(query.select(&games).unwrap().ids, [5]);
+        let query = Query::new("!(sets == 3) || id - 1 >= 3").unwrap();
+        assert_eq!(query.select(&games).unwrap().ids, [4, 5]);
+        let query = Query::new("red * green * blue > 1000").unwrap();
+        assert_eq!(query.select(&games).unwrap().ids, [3]);
+    }
+}
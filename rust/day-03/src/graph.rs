@@ -0,0 +1,145 @@
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::Schematic;
+
+/// A number of the schematic, positions count from 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NumberNode {
+    /// The number.
+    pub value: u32,
+    /// Line it is on.
+    pub line: usize,
+    /// Column of its first digit.
+    pub column: usize,
+    /// How many digits it has.
+    pub length: usize,
+}
+
+/// A symbol of the schematic, positions count from 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolNode {
+    /// The character.
+    pub symbol: char,
+    /// Line it is on.
+    pub line: usize,
+    /// Its column.
+    pub column: usize,
+}
+
+/// Every number and symbol of the schematic, with an edge between each
+/// number and each symbol it touches. A number is a part number exactly
+/// when it has an edge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Graph {
+    /// The numbers, in reading order.
+    pub numbers: Vec<NumberNode>,
+    /// The symbols, in reading order.
+    pub symbols: Vec<SymbolNode>,
+    /// Index of a number and of a symbol next to it.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    /// The symbols next to the number at `index`.
+    pub fn symbols_of(&self, index: usize) -> impl Iterator<Item = &SymbolNode> {
+        self.edges
+            .iter()
+            .filter(move |(number, _)| *number == index)
+            .map(|&(_, symbol)| &self.symbols[symbol])
+    }
+
+    /// The graph as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("el grafo son datos planos")
+    }
+
+    /// The graph in Graphviz's DOT, numbers as ellipses and symbols as
+    /// boxes labelled with their position.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph schematic {\n");
+        for (i, n) in self.numbers.iter().enumerate() {
+            let label = format!("{} ({}:{})", n.value, n.line, n.column);
+            let _ = writeln!(dot, "    n{} [label={:?}];", i, label);
+        }
+        for (i, s) in self.symbols.iter().enumerate() {
+            let label = format!("{} ({}:{})", s.symbol, s.line, s.column);
+            let _ = writeln!(dot, "    s{} [label={:?}, shape=box];", i, label);
+        }
+        for (number, symbol) in &self.edges {
+            let _ = writeln!(dot, "    n{} -- s{};", number, symbol);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Schematic {
+    /// The graph of numbers and the symbols next to them.
+    pub fn graph(&self) -> Graph {
+        let numbers = self
+            .numeros
+            .iter()
+            .map(|numero| NumberNode {
+                value: numero.valor,
+                line: numero.posicion.y as usize + 1,
+                column: numero.posicion.x as usize + 1,
+                length: numero.largo,
+            })
+            .collect();
+        let symbols = self
+            .simbolos
+            .iter()
+            .map(|simbolo| SymbolNode {
+                symbol: simbolo.valor,
+                line: simbolo.posicion.y as usize + 1,
+                column: simbolo.posicion.x as usize + 1,
+            })
+            .collect();
+        let mut edges: Vec<(usize, usize)> = self
+            .simbolos
+            .iter()
+            .enumerate()
+            .flat_map(|(s, simbolo)| {
+                let vecinos = self.vecinos(simbolo.posicion);
+                vecinos.into_iter().map(move |n| (n, s))
+            })
+            .collect();
+        edges.sort_unstable();
+        Graph {
+            numbers,
+            symbols,
+            edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use crate::Day03;
+
+    #[test]
+    fn test_graph() {
+        let schematic = Day03::parse("467..114\n...*....\n..35.#..").unwrap();
+        let graph = schematic.graph();
+        assert_eq!(graph.numbers.len(), 3);
+        assert_eq!(graph.edges, [(0, 0), (2, 0)]);
+        let why: Vec<char> = graph.symbols_of(2).map(|s| s.symbol).collect();
+        assert_eq!(why, ['*']);
+        assert_eq!(graph.symbols_of(1).count(), 0);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("graph schematic {\n    n0 [label=\"467 (1:1)\"];\n"));
+        assert!(dot.contains("    s1 [label=\"# (3:6)\", shape=box];\n"));
+        assert!(dot.ends_with("    n0 -- s0;\n    n2 -- s0;\n}\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        assert_eq!(json["numbers"][2]["value"], 35);
+        assert_eq!(json["numbers"][2]["column"], 3);
+        assert_eq!(json["symbols"][0]["symbol"], "*");
+        assert_eq!(json["edges"][1], serde_json::json!([2, 0]));
+    }
+}
@@ -0,0 +1,210 @@
+//! Day 3: Gear Ratios. Part numbers and gears in the engine schematic, both
+//! special cases of a [`Rule`].
+
+#![warn(missing_docs)]
+
+use std::num::NonZeroU32;
+
+use aoc_core::{grid::Grid, Error, Point, Solution};
+
+/// The bipartite graph of numbers and the symbols next to them.
+pub mod graph;
+/// Rules that pick symbols by their neighbours and add them up.
+pub mod rules;
+
+pub use graph::Graph;
+pub use rules::{Aggregate, Rule, Symbols};
+
+#[derive(Debug)]
+struct Numero {
+    valor: u32,
+    largo: usize,
+    posicion: Point,
+}
+
+#[derive(Debug)]
+struct Simbolo {
+    valor: char,
+    posicion: Point,
+}
+
+/// The engine schematic: the numbers and the symbols between the dots, with
+/// every cell pointing at the number on it.
+#[derive(Debug)]
+pub struct Schematic {
+    numeros: Vec<Numero>,
+    simbolos: Vec<Simbolo>,
+    // index + 1 of the number on each cell, four bytes a cell
+    celdas: Grid<Option<NonZeroU32>>,
+}
+
+impl Schematic {
+    /// Finds the numbers and symbols of the schematic in one pass, an error
+    /// for numbers too big for a `u32`. Lines may have different lengths.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.iter().map(|line| line.chars().count()).max();
+        let mut celdas = Grid::new(width.unwrap_or(0), lines.len(), None);
+        let mut numeros = Vec::new();
+        let mut simbolos = Vec::new();
+        for (y, line) in lines.iter().enumerate() {
+            let mut chars = line.chars().enumerate().peekable();
+            while let Some((x, valor)) = chars.next() {
+                let posicion = Point::new(x as i64, y as i64);
+                if !valor.is_ascii_digit() {
+                    if valor != '.' {
+                        simbolos.push(Simbolo { valor, posicion });
+                    }
+                    continue;
+                }
+                let mut digitos = String::from(valor);
+                while let Some((_, digito)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    digitos.push(digito);
+                }
+                let valor = digitos.parse().map_err(|e| {
+                    Error::parse(Day03::DAY, format!("numero invalido: {}", e)).at(y + 1, x + 1)
+                })?;
+                let indice = u32::try_from(numeros.len() + 1)
+                    .ok()
+                    .and_then(NonZeroU32::new)
+                    .ok_or_else(|| {
+                        Error::parse(Day03::DAY, "demasiados numeros").at(y + 1, x + 1)
+                    })?;
+                for dx in 0..digitos.len() as i64 {
+                    celdas[posicion + Point::new(dx, 0)] = Some(indice);
+                }
+                numeros.push(Numero {
+                    valor,
+                    largo: digitos.len(),
+                    posicion,
+                });
+            }
+        }
+        Ok(Schematic {
+            numeros,
+            simbolos,
+            celdas,
+        })
+    }
+
+    /// The number with a digit on `point`, if any.
+    pub fn number_at(&self, point: Point) -> Option<u32> {
+        let indice = (*self.celdas.get(point)?)?;
+        Some(self.numeros[indice.get() as usize - 1].valor)
+    }
+
+    /// Indices of the numbers next to `point`, each once however many of
+    /// its digits touch it. Looks at the eight cells around it, never
+    /// outside the schematic.
+    fn vecinos(&self, point: Point) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::with_capacity(8);
+        for vecino in self.celdas.neighbors8(point) {
+            if let Some(indice) = self.celdas[vecino] {
+                let indice = indice.get() as usize - 1;
+                if !indices.contains(&indice) {
+                    indices.push(indice);
+                }
+            }
+        }
+        indices
+    }
+
+    /// Sum of the numbers next to a symbol.
+    pub fn part_numbers_sum(&self) -> Result<u64, Error> {
+        self.apply(&Rule::part_numbers())
+    }
+
+    /// Sum of the products of the two numbers next to each gear, a symbol
+    /// with exactly two numbers next to it.
+    pub fn gear_ratios_sum(&self) -> Result<u64, Error> {
+        self.apply(&Rule::gear_ratios())
+    }
+}
+
+/// The [`Solution`] for day 3, the input is the engine schematic.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Schematic;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Schematic, Error> {
+        Schematic::new(input)
+    }
+
+    fn part1(schematic: &Schematic) -> Result<u64, Error> {
+        schematic.part_numbers_sum()
+    }
+
+    fn part2(schematic: &Schematic) -> Result<u64, Error> {
+        schematic.gear_ratios_sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664..598.";
+
+    #[test]
+    fn test_part1() {
+        let schematic = Day03::parse(INPUT).unwrap();
+        assert_eq!(Day03::part1(&schematic), Ok(4361));
+    }
+
+    #[test]
+    fn test_part2() {
+        let schematic = Day03::parse(INPUT).unwrap();
+        assert_eq!(Day03::part2(&schematic), Ok(467835));
+    }
+
+    #[test]
+    fn test_bordes() {
+        let schematic = Day03::parse("*12\n3..\n..7\n.8*").unwrap();
+        assert_eq!(schematic.part_numbers_sum(), Ok(12 + 3 + 7 + 8));
+        assert_eq!(schematic.gear_ratios_sum(), Ok(12 * 3 + 7 * 8));
+        assert_eq!(schematic.number_at(Point::new(2, 0)), Some(12));
+        assert_eq!(schematic.number_at(Point::new(-1, 0)), None);
+        assert_eq!(schematic.number_at(Point::new(0, 1)), Some(3));
+
+        let schematic = Day03::parse("11.\n.#\n#").unwrap();
+        assert_eq!(schematic.part_numbers_sum(), Ok(11));
+        assert_eq!(schematic.gear_ratios_sum(), Ok(0));
+    }
+
+    #[test]
+    fn test_grande() {
+        // every other line has a gear between two one digit numbers every
+        // four cells
+        let input = format!("{}\n{}\n", "1*2.".repeat(200), "....".repeat(200));
+        let input = input.repeat(500);
+        let schematic = Day03::parse(&input).unwrap();
+        assert_eq!(schematic.part_numbers_sum(), Ok(200 * 500 * 3));
+        assert_eq!(schematic.gear_ratios_sum(), Ok(200 * 500 * 2));
+    }
+
+    #[test]
+    fn test_numero_invalido() {
+        let error = Day03::parse("1.\n.*99999999999").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day03>(include_str!("test_input1.txt"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_core::{Error, Solution};
+use clap::{Parser, ValueEnum};
+use day_03::{Aggregate, Day03, Rule, Symbols};
+
+/// Solves both parts on the puzzle input, applies a custom rule or exports
+/// the graph of numbers and the symbols next to them.
+#[derive(Parser)]
+struct Args {
+    /// Symbols the rule looks at, any symbol if not given.
+    #[arg(short, long, requires = "aggregate")]
+    symbols: Option<String>,
+    /// Fewest numbers a symbol needs next to it.
+    #[arg(long, default_value_t = 1, requires = "aggregate")]
+    min: usize,
+    /// Most numbers a symbol may have next to it.
+    #[arg(long, requires = "aggregate")]
+    max: Option<usize>,
+    /// How the numbers next to each symbol are combined.
+    #[arg(short, long, value_enum)]
+    aggregate: Option<AggregateArg>,
+    /// Print the graph of numbers and symbols instead.
+    #[arg(short, long, value_enum, conflicts_with = "aggregate")]
+    graph: Option<GraphFormat>,
+    /// Schematic to read, `-` reads stdin. Defaults to the puzzle input.
+    input: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AggregateArg {
+    Sum,
+    Product,
+    Max,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GraphFormat {
+    Dot,
+    Json,
+}
+
+fn run(args: &Args) -> Result<(), Error> {
+    let input = match &args.input {
+        Some(path) => aoc_core::input::read(path).map_err(|e| {
+            let message = format!("no se pudo leer {}: {}", path.display(), e);
+            Error::read(Day03::DAY, message)
+        })?,
+        None => include_str!("input.txt").to_string(),
+    };
+    let schematic = Day03::parse(&input)?;
+    if let Some(format) = args.graph {
+        let graph = schematic.graph();
+        match format {
+            GraphFormat::Dot => print!("{}", graph.to_dot()),
+            GraphFormat::Json => println!("{}", graph.to_json()),
+        }
+        return Ok(());
+    }
+    let Some(aggregate) = args.aggregate else {
+        let now = std::time::Instant::now();
+        println!(
+            "solutions part 1: {}\n time: {:?} ",
+            Day03::part1(&schematic)?,
+            now.elapsed()
+        );
+        let now = std::time::Instant::now();
+        println!(
+            "solutions part 2: {}\n time: {:?}",
+            Day03::part2(&schematic)?,
+            now.elapsed()
+        );
+        return Ok(());
+    };
+    let rule = Rule {
+        symbols: match &args.symbols {
+            Some(symbols) => Symbols::Only(symbols.chars().collect()),
+            None => Symbols::Any,
+        },
+        neighbours: args.min..=args.max.unwrap_or(usize::MAX),
+        aggregate: match aggregate {
+            AggregateArg::Sum => Aggregate::Sum,
+            AggregateArg::Product => Aggregate::Product,
+            AggregateArg::Max => Aggregate::Max,
+        },
+    };
+    println!("{}", schematic.apply(&rule)?);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(&Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{Error, Solution};
+
+use crate::{Day03, Schematic};
+
+/// Which symbols a [`Rule`] looks at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Symbols {
+    /// Anything that is neither a digit nor a `.`.
+    Any,
+    /// Only these characters.
+    Only(Vec<char>),
+}
+
+impl Symbols {
+    fn contains(&self, simbolo: char) -> bool {
+        match self {
+            Symbols::Any => true,
+            Symbols::Only(simbolos) => simbolos.contains(&simbolo),
+        }
+    }
+}
+
+/// How the numbers next to a symbol become one value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// Added up, 0 without numbers.
+    Sum,
+    /// Multiplied, 1 without numbers.
+    Product,
+    /// The biggest, 0 without numbers.
+    Max,
+}
+
+impl Aggregate {
+    // `None` if the result doesn't fit in a `u64`
+    fn apply(self, mut valores: impl Iterator<Item = u64>) -> Option<u64> {
+        match self {
+            Aggregate::Sum => valores.try_fold(0, u64::checked_add),
+            Aggregate::Product => valores.try_fold(1, u64::checked_mul),
+            Aggregate::Max => Some(valores.max().unwrap_or(0)),
+        }
+    }
+}
+
+/// Picks the symbols of a class with a number of numbers next to them,
+/// aggregates the numbers of each and adds that up over the symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// The symbols looked at.
+    pub symbols: Symbols,
+    /// How many different numbers a symbol needs next to it.
+    pub neighbours: RangeInclusive<usize>,
+    /// What each symbol is worth.
+    pub aggregate: Aggregate,
+}
+
+impl Rule {
+    /// Part 1: every number next to any symbol, once for each symbol.
+    pub fn part_numbers() -> Self {
+        Rule {
+            symbols: Symbols::Any,
+            neighbours: 1..=usize::MAX,
+            aggregate: Aggregate::Sum,
+        }
+    }
+
+    /// Part 2: the product of the numbers next to any symbol with exactly
+    /// two of them. The puzzle only means `*`, which
+    /// `Symbols::Only(vec!['*'])` gives.
+    pub fn gear_ratios() -> Self {
+        Rule {
+            symbols: Symbols::Any,
+            neighbours: 2..=2,
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+impl Schematic {
+    /// Every symbol `rule` picks and what it is worth, an error at the
+    /// symbol if that doesn't fit in a `u64`.
+    pub fn matches<'a>(
+        &'a self,
+        rule: &'a Rule,
+    ) -> impl Iterator<Item = Result<(char, u64), Error>> + 'a {
+        self.simbolos
+            .iter()
+            .filter(|simbolo| rule.symbols.contains(simbolo.valor))
+            .filter_map(|simbolo| {
+                let vecinos = self.vecinos(simbolo.posicion);
+                if !rule.neighbours.contains(&vecinos.len()) {
+                    return None;
+                }
+                let valores = vecinos.iter().map(|&i| u64::from(self.numeros[i].valor));
+                let valor = rule.aggregate.apply(valores).ok_or_else(|| {
+                    let message =
+                        format!("los numeros junto a {} no entran en un u64", simbolo.valor);
+                    let (x, y) = (simbolo.posicion.x as usize, simbolo.posicion.y as usize);
+                    Error::solve(Day03::DAY, message).at(y + 1, x + 1)
+                });
+                Some(valor.map(|valor| (simbolo.valor, valor)))
+            })
+    }
+
+    /// Sum of what every symbol `rule` picks is worth, an error if it
+    /// doesn't fit in a `u64`.
+    pub fn apply(&self, rule: &Rule) -> Result<u64, Error> {
+        self.matches(rule).try_fold(0u64, |total, valor| {
+            let (_, valor) = valor?;
+            total
+                .checked_add(valor)
+                .ok_or_else(|| Error::solve(Day03::DAY, "la suma no entra en un u64"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664..598.";
+
+    #[test]
+    fn test_rules() {
+        let schematic = Day03::parse(INPUT).unwrap();
+        assert_eq!(schematic.apply(&Rule::part_numbers()), Ok(4361));
+        assert_eq!(schematic.apply(&Rule::gear_ratios()), Ok(467835));
+
+        let stars = Rule {
+            symbols: Symbols::Only(vec!['*']),
+            ..Rule::part_numbers()
+        };
+        assert_eq!(schematic.apply(&stars), Ok(467 + 35 + 617 + 755 + 598));
+        let biggest = Rule {
+            aggregate: Aggregate::Max,
+            ..Rule::part_numbers()
+        };
+        assert_eq!(
+            schematic.apply(&biggest),
+            Ok(467 + 633 + 617 + 592 + 664 + 755)
+        );
+        let lonely = Rule {
+            neighbours: 0..=0,
+            ..Rule::gear_ratios()
+        };
+        assert_eq!(schematic.matches(&lonely).count(), 0);
+        let symbols: Vec<char> = schematic
+            .matches(&Rule::gear_ratios())
+            .map(|m| m.unwrap().0)
+            .collect();
+        assert_eq!(symbols, ['*', '*']);
+    }
+
+    #[test]
+    fn test_gears_any_symbol() {
+        let schematic = Day03::parse("2#3\n...\n4*5").unwrap();
+        assert_eq!(schematic.apply(&Rule::gear_ratios()), Ok(6 + 20));
+        let gears = Rule {
+            symbols: Symbols::Only(vec!['*']),
+            ..Rule::gear_ratios()
+        };
+        assert_eq!(schematic.apply(&gears), Ok(20));
+    }
+
+    #[test]
+    fn test_desborde() {
+        let big = "4294967295";
+        let dots = ".".repeat(10);
+        let input = format!("{big}.{big}\n{dots}*{dots}\n{big}.{big}");
+        let schematic = Day03::parse(&input).unwrap();
+        assert!(schematic.apply(&Rule::part_numbers()).is_ok());
+        let product = Rule {
+            neighbours: 1..=4,
+            ..Rule::gear_ratios()
+        };
+        let error = schematic.apply(&product).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(11)));
+
+        let input = format!("{big}*{big}\n{dots}.{dots}\n{big}*{big}");
+        let schematic = Day03::parse(&input).unwrap();
+        assert_eq!(schematic.matches(&Rule::gear_ratios()).count(), 2);
+        let error = schematic.apply(&Rule::gear_ratios()).unwrap_err();
+        assert_eq!(error.message, "la suma no entra en un u64");
+    }
+}
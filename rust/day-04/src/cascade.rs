@@ -0,0 +1,155 @@
+use std::fmt;
+
+use aoc_core::{Error, Solution};
+
+use crate::{Card, Day04};
+
+/// How many copies of every card there are in the end and which earlier
+/// cards won them. Cards win copies of the cards after them by position,
+/// never past the last card, and the counts are checked so a long list
+/// fails instead of wrapping around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    ids: Vec<u32>,
+    copias: Vec<u64>,
+    // for each card, the index of every earlier card that won it copies and
+    // how many
+    origen: Vec<Vec<(usize, u64)>>,
+}
+
+impl Cascade {
+    /// Plays the cards in order, an error if a count doesn't fit a `u64`.
+    pub fn new(cards: &[Card]) -> Result<Self, Error> {
+        Self::with_limits(cards, None, None)
+    }
+
+    /// Like [`Cascade::new`], a card wins copies of at most the `window`
+    /// cards after it and no card gets more than `cap` copies besides the
+    /// original. Copies past the cap are lost.
+    pub fn with_limits(
+        cards: &[Card],
+        window: Option<usize>,
+        cap: Option<u64>,
+    ) -> Result<Self, Error> {
+        let tope = cap.map_or(u64::MAX, |cap| cap.saturating_add(1));
+        let mut copias = vec![1u64; cards.len()];
+        let mut origen = vec![Vec::new(); cards.len()];
+        for (i, card) in cards.iter().enumerate() {
+            let ganadas = copias[i];
+            let alcance = (card.ganadores() as usize).min(window.unwrap_or(usize::MAX));
+            let hasta = (i + 1).saturating_add(alcance).min(cards.len());
+            for j in i + 1..hasta {
+                let suma = copias[j].checked_add(ganadas).ok_or_else(|| {
+                    let message = format!("demasiadas copias de la carta {}", cards[j].id);
+                    Error::solve(Day04::DAY, message)
+                })?;
+                let nuevas = suma.min(tope) - copias[j];
+                if nuevas > 0 {
+                    copias[j] += nuevas;
+                    origen[j].push((i, nuevas));
+                }
+            }
+        }
+        Ok(Cascade {
+            ids: cards.iter().map(Card::id).collect(),
+            copias,
+            origen,
+        })
+    }
+
+    /// How many of the card at `index` there are, the original included.
+    pub fn copies(&self, index: usize) -> u64 {
+        self.copias[index]
+    }
+
+    /// The ids of the earlier cards that won copies of the card at `index`
+    /// and how many each one won.
+    pub fn provenance(&self, index: usize) -> impl Iterator<Item = (u32, u64)> + '_ {
+        self.origen[index].iter().map(|&(i, n)| (self.ids[i], n))
+    }
+
+    /// How many cards there are in all, an error if it doesn't fit a `u64`.
+    pub fn total(&self) -> Result<u64, Error> {
+        self.copias.iter().try_fold(0u64, |total, &n| {
+            total
+                .checked_add(n)
+                .ok_or_else(|| Error::solve(Day04::DAY, "demasiadas cartas"))
+        })
+    }
+}
+
+// Card 4: 8 = 1 + 1 (Card 1) + 2 (Card 2) + 4 (Card 3)
+impl fmt::Display for Cascade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, id) in self.ids.iter().enumerate() {
+            write!(f, "Card {}: {} = 1", id, self.copias[i])?;
+            for (origen, n) in self.provenance(i) {
+                write!(f, " + {} (Card {})", n, origen)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cascade() {
+        let cards = Day04::parse(include_str!("input_test.txt")).unwrap();
+        let cascade = Cascade::new(&cards).unwrap();
+        assert_eq!(cascade.total(), Ok(30));
+        let copies: Vec<u64> = (0..cards.len()).map(|i| cascade.copies(i)).collect();
+        assert_eq!(copies, [1, 2, 4, 8, 14, 1]);
+        let provenance: Vec<(u32, u64)> = cascade.provenance(4).collect();
+        assert_eq!(provenance, [(1, 1), (3, 4), (4, 8)]);
+        for i in 0..cards.len() {
+            let sum: u64 = cascade.provenance(i).map(|(_, n)| n).sum();
+            assert_eq!(cascade.copies(i), 1 + sum);
+        }
+        let report = cascade.to_string();
+        assert!(report.starts_with("Card 1: 1 = 1\nCard 2: 2 = 1 + 1 (Card 1)\n"));
+        assert!(report.contains("Card 5: 14 = 1 + 1 (Card 1) + 4 (Card 3) + 8 (Card 4)\n"));
+    }
+
+    #[test]
+    fn test_limits() {
+        let cards = Day04::parse(include_str!("input_test.txt")).unwrap();
+        let window = Cascade::with_limits(&cards, Some(1), None).unwrap();
+        let copies: Vec<u64> = (0..cards.len()).map(|i| window.copies(i)).collect();
+        assert_eq!(copies, [1, 2, 3, 4, 5, 1]);
+        let capped = Cascade::with_limits(&cards, None, Some(2)).unwrap();
+        let copies: Vec<u64> = (0..cards.len()).map(|i| capped.copies(i)).collect();
+        assert_eq!(copies, [1, 2, 3, 3, 3, 1]);
+        let provenance: Vec<(u32, u64)> = capped.provenance(3).collect();
+        assert_eq!(provenance, [(1, 1), (2, 1)]);
+        let none = Cascade::with_limits(&cards, Some(0), None).unwrap();
+        assert_eq!(none.total(), Ok(6));
+    }
+
+    #[test]
+    fn test_sin_cartas_despues() {
+        // the last card wins copies of cards that don't exist
+        let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 4 | 3 4").unwrap();
+        let cascade = Cascade::new(&cards).unwrap();
+        assert_eq!(cascade.total(), Ok(3));
+    }
+
+    #[test]
+    fn test_desborde() {
+        // every card wins a copy of the next 70, the counts double each card
+        let numeros = (1..=70)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input: String = (1..=80)
+            .map(|id| format!("Card {}: {} | {}\n", id, numeros, numeros))
+            .collect();
+        let cards = Day04::parse(&input).unwrap();
+        let error = Cascade::new(&cards).unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Solve);
+        assert!(Cascade::new(&cards[..60]).is_ok());
+    }
+}
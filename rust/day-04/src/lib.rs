@@ -0,0 +1,183 @@
+//! Day 4: Scratchcards. Winning numbers and the copies they win, scored
+//! any of the ways in [`Scoring`].
+
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, fmt};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space0, space1},
+    multi::fold_many1,
+    sequence::{delimited, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+use aoc_core::{parse, Error, Solution};
+
+/// The copies every card ends up with and where they come from.
+pub mod cascade;
+/// The ways a deck can be scored.
+pub mod scoring;
+
+pub use cascade::Cascade;
+pub use scoring::Scoring;
+
+/// A scratchcard, its winning numbers and the numbers it has.
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    numeros_ganadores: HashSet<u32>,
+    numeros: HashSet<u32>,
+}
+
+impl Card {
+    /// The card's number.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// How many of the card's numbers are winning numbers.
+    pub fn ganadores(&self) -> u32 {
+        self.numeros_ganadores.intersection(&self.numeros).count() as u32
+    }
+
+    /// Points of the card, 1 for the first match and doubled for every
+    /// other, `None` if that doesn't fit a `u64`.
+    pub fn puntaje(&self) -> Option<u64> {
+        match self.ganadores() {
+            0 => Some(0),
+            cantidad => 1u64.checked_shl(cantidad - 1),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (ganadores, numeros) = (numeros(&self.numeros_ganadores), numeros(&self.numeros));
+        write!(f, "Card {}: {} | {}", self.id, ganadores, numeros)
+    }
+}
+
+fn numeros(set: &HashSet<u32>) -> String {
+    let mut numeros = set.iter().collect::<Vec<_>>();
+    numeros.sort();
+    numeros
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn set(input: &str) -> IResult<&str, HashSet<u32>> {
+    fold_many1(
+        terminated(complete::u32, space0),
+        HashSet::new,
+        |mut acc: HashSet<_>, item| {
+            acc.insert(item);
+            acc
+        },
+    )(input)
+}
+
+//Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn card(input: &str) -> IResult<&str, Card> {
+    let (input, id) = delimited(
+        tuple((tag("Card"), space1)),
+        complete::u32,
+        tuple((tag(":"), space1)),
+    )(input)?;
+    separated_pair(set, tuple((tag("|"), space1)), set)
+        .map(|(numeros_ganadores, numeros)| Card {
+            id,
+            numeros_ganadores,
+            numeros,
+        })
+        .parse(input)
+}
+
+/// Parses the cards and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let cards = Day04::parse(input)?;
+    Ok(cards.iter().map(|card| format!("{}\n", card)).collect())
+}
+
+/// The [`Solution`] for day 4, the input is the list of cards.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Card>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Card>, Error> {
+        parse::lines(Self::DAY, input, card)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<u64, Error> {
+        Scoring::Doubling.score(cards)
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<u64, Error> {
+        Scoring::Cascade {
+            window: None,
+            cap: None,
+        }
+        .score(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<(), Error> {
+        let cards = Day04::parse(include_str!("input_test.txt"))?;
+        assert_eq!(Day04::part1(&cards)?, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), Error> {
+        let cards = Day04::parse(include_str!("input_test.txt"))?;
+        assert_eq!(Day04::part2(&cards)?, 30);
+        Ok(())
+    }
+
+    #[test]
+    fn test_puntaje() {
+        let numeros = (1..=64)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let cards = Day04::parse(&format!("Card 1: {} | {}", numeros, numeros)).unwrap();
+        assert_eq!(cards[0].puntaje(), Some(1 << 63));
+        let numeros = format!("{} 65", numeros);
+        let cards = Day04::parse(&format!("Card 1: {} | {}", numeros, numeros)).unwrap();
+        assert_eq!(cards[0].puntaje(), None);
+        assert!(Day04::part1(&cards).is_err());
+    }
+
+    #[test]
+    fn test_error() {
+        let error = Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 13 x | 61").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(12)));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "Card 7: 41 48 41 | 83  6\nCard  8:  1 | 2";
+        let written = rewrite(input).unwrap();
+        assert_eq!(written, "Card 7: 41 48 | 6 83\nCard 8: 1 | 2\n");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day04>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::process::ExitCode;
+
+use aoc_core::{Error, Solution};
+use clap::{error::ErrorKind, CommandFactory, Parser, ValueEnum};
+use day_04::{Cascade, Day04, Scoring};
+
+/// Solves both parts on the puzzle input, or scores it some other way.
+#[derive(Parser)]
+struct Args {
+    /// Print how many copies of each card there are and which earlier cards
+    /// won them.
+    #[arg(short, long, conflicts_with = "scoring")]
+    provenance: bool,
+    /// Score the cards this way instead of solving both parts.
+    #[arg(short, long, value_enum)]
+    scoring: Option<Strategy>,
+    /// Most cards after it a card can win copies of, for `cascade` and
+    /// `--provenance`.
+    #[arg(short, long)]
+    window: Option<usize>,
+    /// Most copies of a card that can be won, for `cascade` and
+    /// `--provenance`.
+    #[arg(short, long)]
+    cap: Option<u64>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Strategy {
+    /// 1 point for the first match, doubled for every other (part 1).
+    Doubling,
+    /// 1 point for every match.
+    Linear,
+    /// Matches win copies of the cards after them (part 2), limited by
+    /// `--window` and `--cap`.
+    Cascade,
+}
+
+impl Args {
+    // `--window` and `--cap` only change the cascade
+    fn check(&self) -> Result<(), clap::Error> {
+        let cascade = self.provenance || matches!(self.scoring, Some(Strategy::Cascade));
+        if !cascade && (self.window.is_some() || self.cap.is_some()) {
+            let message = "--window y --cap solo sirven con --scoring cascade o --provenance";
+            return Err(Args::command().error(ErrorKind::ArgumentConflict, message));
+        }
+        Ok(())
+    }
+}
+
+fn run(args: &Args) -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let cards = Day04::parse(input)?;
+    if let Some(strategy) = args.scoring {
+        let scoring = match strategy {
+            Strategy::Doubling => Scoring::Doubling,
+            Strategy::Linear => Scoring::Linear,
+            Strategy::Cascade => Scoring::Cascade {
+                window: args.window,
+                cap: args.cap,
+            },
+        };
+        println!("{}", scoring.score(&cards)?);
+        return Ok(());
+    }
+    if args.provenance {
+        let cascade = Cascade::with_limits(&cards, args.window, args.cap)?;
+        print!("{}", cascade);
+        println!("total: {}", cascade.total()?);
+        return Ok(());
+    }
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day04::part1(&cards)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day04::part2(&cards)?,
+        now.elapsed()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Err(error) = args.check() {
+        error.exit();
+    }
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
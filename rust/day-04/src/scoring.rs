@@ -0,0 +1,60 @@
+use aoc_core::{Error, Solution};
+
+use crate::{Card, Cascade, Day04};
+
+/// How a deck of cards is scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scoring {
+    /// Part 1: 1 point for the first match, doubled for every other.
+    Doubling,
+    /// 1 point for every match.
+    Linear,
+    /// Part 2 without limits: every match wins a copy of a card after it, at
+    /// most `window` of them, and the score is how many cards there are in
+    /// the end. See [`Cascade::with_limits`].
+    Cascade {
+        /// Most cards after it a card can win copies of.
+        window: Option<usize>,
+        /// Most copies of a card that can be won.
+        cap: Option<u64>,
+    },
+}
+
+impl Scoring {
+    /// The score of `cards`, an error if it doesn't fit a `u64`.
+    pub fn score(&self, cards: &[Card]) -> Result<u64, Error> {
+        match *self {
+            Scoring::Doubling => sum(cards, Card::puntaje),
+            Scoring::Linear => sum(cards, |card| Some(u64::from(card.ganadores()))),
+            Scoring::Cascade { window, cap } => Cascade::with_limits(cards, window, cap)?.total(),
+        }
+    }
+}
+
+fn sum(cards: &[Card], puntaje: impl Fn(&Card) -> Option<u64>) -> Result<u64, Error> {
+    cards.iter().try_fold(0u64, |total, card| {
+        puntaje(card)
+            .and_then(|puntaje| total.checked_add(puntaje))
+            .ok_or_else(|| {
+                let message = format!("la carta {} vale demasiados puntos", card.id);
+                Error::solve(Day04::DAY, message)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoring() {
+        let cards = Day04::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(Scoring::Doubling.score(&cards), Ok(13));
+        assert_eq!(Scoring::Linear.score(&cards), Ok(4 + 2 + 2 + 1));
+        let cascade = |window, cap| Scoring::Cascade { window, cap }.score(&cards);
+        assert_eq!(cascade(None, None), Ok(30));
+        assert_eq!(cascade(Some(1), None), Ok(16));
+        assert_eq!(cascade(None, Some(2)), Ok(13));
+        assert_eq!(cascade(None, Some(u64::MAX)), Ok(30));
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use aoc_core::{Error, Solution};
+
+use crate::{Almanac, Day05, RangeMap};
+
+/// The category the seeds are in, every other one has to be reachable from it.
+pub const SEED: &str = "seed";
+
+/// A path through the almanac, the maps in the order they are followed.
+pub type Path<'m, 'a> = Vec<&'m RangeMap<'a>>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    Open,
+    Closed,
+}
+
+impl<'a> Almanac<'a> {
+    /// Every category of the almanac, in the order they first show up.
+    pub fn categories(&self) -> Vec<&'a str> {
+        let mut seen = BTreeSet::new();
+        let mut categories = Vec::new();
+        for range_map in &self.range_maps {
+            for category in [range_map.source, range_map.destination] {
+                if seen.insert(category) {
+                    categories.push(category);
+                }
+            }
+        }
+        categories
+    }
+
+    /// The maps that start at `source`, in the order of the almanac.
+    pub fn maps_from<'m, 's>(
+        &'m self,
+        source: &'s str,
+    ) -> impl Iterator<Item = &'m RangeMap<'a>> + use<'m, 's, 'a> {
+        self.range_maps
+            .iter()
+            .filter(move |range_map| range_map.source == source)
+    }
+
+    /// Checks the maps make a graph without repeated maps or cycles where
+    /// every category can be reached from [`SEED`]. `input` is the text the
+    /// almanac was read from, the errors point at the header of the map at
+    /// fault.
+    pub fn check(&self, input: &str) -> Result<(), Error> {
+        let error = |range_map: &RangeMap, message: String| {
+            Err(Error::parse(Day05::DAY, message).at_rest(input, range_map.source))
+        };
+        let mut pairs = BTreeSet::new();
+        for range_map in &self.range_maps {
+            if !pairs.insert((range_map.source, range_map.destination)) {
+                let (source, destination) = (range_map.source, range_map.destination);
+                let message = format!("el mapa {}-to-{} esta repetido", source, destination);
+                return error(range_map, message);
+            }
+        }
+
+        let mut visits = BTreeMap::new();
+        for category in self.categories() {
+            let mut path = Vec::new();
+            if let Some(cycle) = self.cycle(category, &mut visits, &mut path) {
+                // the map that closes it
+                let [.., source, destination] = cycle[..] else {
+                    unreachable!("un ciclo tiene al menos una categoria repetida");
+                };
+                let closing = self
+                    .maps_from(source)
+                    .find(|m| m.destination == destination);
+                let closing = closing.expect("el ciclo sigue los mapas");
+                return error(closing, format!("hay un ciclo: {}", cycle.join(" -> ")));
+            }
+        }
+
+        let mut reached = BTreeSet::from([SEED]);
+        let mut pending = vec![SEED];
+        while let Some(category) = pending.pop() {
+            for range_map in self.maps_from(category) {
+                if reached.insert(range_map.destination) {
+                    pending.push(range_map.destination);
+                }
+            }
+        }
+        // the first category not reached shows up first as the source of a
+        // map, or that map's source would come before it
+        match self.categories().into_iter().find(|c| !reached.contains(c)) {
+            Some(category) => {
+                let first = self.maps_from(category).next().expect("aparece en un mapa");
+                error(first, format!("no se llega a {} desde {}", category, SEED))
+            }
+            None => Ok(()),
+        }
+    }
+
+    // depth first, the categories of the first cycle found from `category`
+    // with the first one repeated at the end
+    fn cycle(
+        &self,
+        category: &'a str,
+        visits: &mut BTreeMap<&'a str, Visit>,
+        path: &mut Vec<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        path.push(category);
+        match visits.get(category) {
+            Some(Visit::Closed) => {
+                path.pop();
+                return None;
+            }
+            Some(Visit::Open) => {
+                let start = path.iter().position(|&c| c == category)?;
+                return Some(path[start..].to_vec());
+            }
+            None => {}
+        }
+        visits.insert(category, Visit::Open);
+        for range_map in self.maps_from(category) {
+            if let Some(cycle) = self.cycle(range_map.destination, visits, path) {
+                return Some(cycle);
+            }
+        }
+        visits.insert(category, Visit::Closed);
+        path.pop();
+        None
+    }
+
+    /// Every way to go from category `from` to `to`, the empty path if they
+    /// are the same. Only ends for almanacs that pass [`Almanac::check`].
+    pub fn paths(&self, from: &str, to: &str) -> Vec<Path<'_, 'a>> {
+        let mut paths = Vec::new();
+        self.paths_into(from, to, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    fn paths_into<'m>(
+        &'m self,
+        from: &str,
+        to: &str,
+        path: &mut Path<'m, 'a>,
+        paths: &mut Vec<Path<'m, 'a>>,
+    ) {
+        if from == to {
+            paths.push(path.clone());
+            return;
+        }
+        for range_map in self.maps_from(from) {
+            path.push(range_map);
+            self.paths_into(range_map.destination, to, path, paths);
+            path.pop();
+        }
+    }
+
+    // the categories reachable from `category`, each after the ones its maps
+    // go to
+    fn postorder(&self, category: &'a str, seen: &mut HashSet<&'a str>, order: &mut Vec<&'a str>) {
+        if !seen.insert(category) {
+            return;
+        }
+        for range_map in self.maps_from(category) {
+            self.postorder(range_map.destination, seen, order);
+        }
+        order.push(category);
+    }
+
+    /// Every path from category `from` to `to` folded into one map, in the
+    /// order of [`Almanac::paths`]. The maps from each category on are
+    /// composed once and shared by every path through it.
+    pub fn chains(&self, from: &'a str, to: &str) -> Result<Vec<RangeMap<'a>>, Error> {
+        let mut order = Vec::new();
+        self.postorder(from, &mut HashSet::new(), &mut order);
+        let mut chains: HashMap<&str, Vec<RangeMap<'a>>> = HashMap::new();
+        for category in order {
+            let mut from_here = Vec::new();
+            if category == to {
+                from_here.push(RangeMap::new(category, category));
+            } else {
+                for range_map in self.maps_from(category) {
+                    for rest in &chains[range_map.destination] {
+                        from_here.push(range_map.compose(rest)?);
+                    }
+                }
+            }
+            chains.insert(category, from_here);
+        }
+        match chains.remove(from) {
+            Some(chains) if !chains.is_empty() => Ok(chains),
+            _ => {
+                let message = format!("no se puede ir de {} a {}", from, to);
+                Err(Error::solve(Day05::DAY, message))
+            }
+        }
+    }
+
+    /// Where `valor` of category `from` ends in category `to` along each
+    /// path, in the order of [`Almanac::paths`].
+    pub fn follow(&self, valor: u64, from: &'a str, to: &str) -> Result<Vec<u64>, Error> {
+        let chains = self.chains(from, to)?;
+        Ok(chains.iter().map(|chain| chain.get(valor)).collect())
+    }
+
+    /// Like [`Almanac::follow`] for every number of `ranges` at once, the
+    /// ranges every path ends in put together.
+    pub fn follow_ranges(
+        &self,
+        ranges: &[RangeInclusive<u64>],
+        from: &'a str,
+        to: &str,
+    ) -> Result<Vec<RangeInclusive<u64>>, Error> {
+        let chains = self.chains(from, to)?;
+        Ok(chains
+            .iter()
+            .flat_map(|chain| {
+                ranges
+                    .iter()
+                    .flat_map(|range| chain.get_range(range.clone()))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LOCATION;
+
+    const BRANCHES: &str = "seeds: 1 20
+
+seed-to-soil map:
+10 0 5
+
+seed-to-sand map:
+100 0 10
+
+soil-to-location map:
+0 10 3
+
+sand-to-location map:
+7 100 1";
+
+    #[test]
+    fn test_paths() {
+        let almanac = Day05::parse(BRANCHES).unwrap();
+        assert_eq!(almanac.categories(), ["seed", "soil", "sand", "location"]);
+        let names = |path: &Path| {
+            let names: Vec<_> = path.iter().map(|m| m.destination()).collect();
+            names.join(",")
+        };
+        let paths: Vec<_> = almanac.paths(SEED, LOCATION).iter().map(names).collect();
+        assert_eq!(paths, ["soil,location", "sand,location"]);
+        assert_eq!(almanac.paths("soil", "soil").len(), 1);
+        assert!(almanac.paths("soil", "sand").is_empty());
+
+        assert_eq!(almanac.follow(0, SEED, LOCATION), Ok(vec![0, 7]));
+        assert_eq!(almanac.follow(1, SEED, LOCATION), Ok(vec![1, 101]));
+        assert_eq!(almanac.follow(9, "sand", "sand"), Ok(vec![9]));
+        assert!(almanac.follow(1, "soil", "sand").is_err());
+        check_paths(&almanac);
+        assert_eq!(
+            almanac.follow_ranges(&[0..=1], SEED, LOCATION),
+            Ok(vec![0..=1, 7..=7, 101..=101])
+        );
+
+        assert_eq!(Day05::part1(&almanac), Ok(1));
+        // seed 10 goes to soil 10 and then to location 0
+        assert_eq!(Day05::part2(&almanac), Ok(0));
+    }
+
+    // the chains give what walking every path map by map gives
+    fn check_paths(almanac: &Almanac) {
+        let paths = almanac.paths(SEED, LOCATION);
+        assert_eq!(almanac.chains(SEED, LOCATION).unwrap().len(), paths.len());
+        for seed in 0..120 {
+            let walked: Vec<_> = paths
+                .iter()
+                .map(|path| path.iter().fold(seed, |valor, m| m.get(valor)))
+                .collect();
+            assert_eq!(almanac.follow(seed, SEED, LOCATION), Ok(walked));
+        }
+    }
+
+    #[test]
+    fn test_diamonds() {
+        // two ways through each diamond, eight paths sharing their maps
+        let mut input = "seeds: 1".to_string();
+        let categories = [
+            "seed", "a", "b", "c", "d", "e", "f", "g", "h", "i", LOCATION,
+        ];
+        for (n, pair) in categories.windows(2).enumerate() {
+            input += &format!(
+                "\n\n{}-to-{} map:\n{} {} 10",
+                pair[0],
+                pair[1],
+                n * 7,
+                n * 3
+            );
+        }
+        for n in [0, 3, 6] {
+            let (a, b) = (categories[n], categories[n + 3]);
+            input += &format!("\n\n{}-to-{} map:\n{} {} 20", a, b, 100 - n, n * 5);
+        }
+        let almanac = Day05::parse(&input).unwrap();
+        assert_eq!(almanac.paths(SEED, LOCATION).len(), 8);
+        check_paths(&almanac);
+    }
+
+    #[test]
+    fn test_check() {
+        let error = |input: &str| {
+            let error = Day05::parse(input).unwrap_err();
+            assert_eq!(error.column, Some(1));
+            (error.line.unwrap(), error.message)
+        };
+        let input = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nseed-to-soil map:\n4 5 6";
+        let message = "el mapa seed-to-soil esta repetido".to_string();
+        assert_eq!(error(input), (6, message));
+        let input = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n4 5 6";
+        let message = "hay un ciclo: seed -> soil -> seed".to_string();
+        assert_eq!(error(input), (6, message));
+        let input = "seeds: 1\n\nseed-to-seed map:\n1 2 3";
+        assert_eq!(error(input), (3, "hay un ciclo: seed -> seed".to_string()));
+        let input = "seeds: 1\n\nsoil-to-water map:\n1 2 3\n\nseed-to-soil map:\n4 5 6\n\n\
+                     water-to-soil map:\n7 8 9";
+        let message = "hay un ciclo: soil -> water -> soil".to_string();
+        assert_eq!(error(input), (9, message));
+        let input = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n4 5 6";
+        assert_eq!(
+            error(input),
+            (6, "no se llega a water desde seed".to_string())
+        );
+    }
+}
@@ -0,0 +1,549 @@
+//! Day 5: If You Give A Seed A Fertilizer. Mapping seeds through the almanac
+//! categories down to locations. The maps make a graph of categories, a seed
+//! can reach a location along more than one path.
+
+#![warn(missing_docs)]
+
+use std::{fmt, ops::RangeInclusive};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, space1},
+    combinator::{eof, map_opt, verify},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use aoc_core::{parse, Error, Solution};
+
+/// The categories as a graph, checks and queries along every path.
+pub mod graph;
+pub use graph::{Path, SEED};
+
+/// The category the almanac ends in.
+pub const LOCATION: &str = "location";
+
+/// One map of the almanac, from the numbers of a category to the ones of
+/// another. Numbers outside every range keep their value. The ranges are kept
+/// sorted and without overlaps, so finding one is a binary search.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeMap<'a> {
+    source: &'a str,
+    destination: &'a str,
+    data: Vec<(RangeInclusive<u64>, u64)>,
+}
+
+impl<'a> RangeMap<'a> {
+    /// An empty map from category `source` to `destination`.
+    pub fn new(source: &'a str, destination: &'a str) -> RangeMap<'a> {
+        RangeMap {
+            source,
+            destination,
+            data: Vec::new(),
+        }
+    }
+
+    /// The category the map goes from.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The category the map goes to.
+    pub fn destination(&self) -> &'a str {
+        self.destination
+    }
+
+    /// Maps `range` to the numbers starting at `value`. Returns `false` and
+    /// leaves the map as it was if `range` is empty, overlaps a range already
+    /// in the map or its image doesn't fit in a `u64`.
+    pub fn insert(&mut self, range: RangeInclusive<u64>, value: u64) -> bool {
+        if range.is_empty() || value.checked_add(range.end() - range.start()).is_none() {
+            return false;
+        }
+        let i = self.position(*range.start());
+        if let Some((next, _)) = self.data.get(i) {
+            if next.start() <= range.end() {
+                return false;
+            }
+        }
+        self.data.insert(i, (range, value));
+        true
+    }
+
+    // the first range that doesn't end before `index`
+    fn position(&self, index: u64) -> usize {
+        self.data.partition_point(|(range, _)| *range.end() < index)
+    }
+
+    /// Where the map sends `index`.
+    pub fn get(&self, index: u64) -> u64 {
+        match self.data.get(self.position(index)) {
+            Some((range, value)) if range.contains(&index) => value + (index - range.start()),
+            _ => index,
+        }
+    }
+
+    /// `index_range` split in the pieces that stay together, each with where
+    /// its first number goes. The numbers no range covers are pieces too.
+    fn pieces(&self, index_range: RangeInclusive<u64>) -> Vec<(RangeInclusive<u64>, u64)> {
+        let mut pieces = Vec::new();
+        if index_range.is_empty() {
+            return pieces;
+        }
+        let (mut start, end) = index_range.into_inner();
+        let mut i = self.position(start);
+        loop {
+            match self.data.get(i) {
+                Some((range, value)) if *range.start() <= end => {
+                    if start < *range.start() {
+                        pieces.push((start..=range.start() - 1, start));
+                        start = *range.start();
+                    }
+                    let last = end.min(*range.end());
+                    pieces.push((start..=last, value + (start - range.start())));
+                    if last == end {
+                        return pieces;
+                    }
+                    start = last + 1;
+                    i += 1;
+                }
+                _ => {
+                    pieces.push((start..=end, start));
+                    return pieces;
+                }
+            }
+        }
+    }
+
+    /// Where the map sends every number of `index_range`, split in the
+    /// ranges that stay together.
+    pub fn get_range(&self, index_range: RangeInclusive<u64>) -> Vec<RangeInclusive<u64>> {
+        self.pieces(index_range)
+            .into_iter()
+            .map(|(range, value)| value..=value + (range.end() - range.start()))
+            .collect()
+    }
+
+    // a map from pieces sorted by where they start, leaving out the ones
+    // that keep their value and joining the ones that go on one another
+    fn from_pieces(
+        source: &'a str,
+        destination: &'a str,
+        pieces: impl IntoIterator<Item = (RangeInclusive<u64>, u64)>,
+    ) -> RangeMap<'a> {
+        let mut range_map = RangeMap::new(source, destination);
+        for (range, value) in pieces {
+            if *range.start() == value {
+                continue;
+            }
+            if let Some((last, last_value)) = range_map.data.last_mut() {
+                let joined = last.end().checked_add(1) == Some(*range.start())
+                    && last_value.checked_add(last.end() - last.start() + 1) == Some(value);
+                if joined {
+                    *last = *last.start()..=*range.end();
+                    continue;
+                }
+            }
+            range_map.data.push((range, value));
+        }
+        range_map
+    }
+
+    /// The map that does `self` and then `next`, from the source of `self` to
+    /// the destination of `next`. An error if `next` doesn't start where
+    /// `self` ends.
+    pub fn compose(&self, next: &RangeMap<'a>) -> Result<RangeMap<'a>, Error> {
+        if self.destination != next.source {
+            let message = format!(
+                "no se puede seguir el mapa {}-to-{} con el {}-to-{}",
+                self.source, self.destination, next.source, next.destination
+            );
+            return Err(Error::solve(Day05::DAY, message));
+        }
+        let mut pieces = Vec::new();
+        for (range, value) in self.pieces(0..=u64::MAX) {
+            let image = value..=value + (range.end() - range.start());
+            for (middle, last) in next.pieces(image) {
+                let start = range.start() + (middle.start() - value);
+                let end = range.start() + (middle.end() - value);
+                pieces.push((start..=end, last));
+            }
+        }
+        Ok(RangeMap::from_pieces(self.source, next.destination, pieces))
+    }
+
+    /// The map back from the destination to the source, an error if two
+    /// numbers go to the same one.
+    pub fn invert(&self) -> Result<RangeMap<'a>, Error> {
+        let mut pieces: Vec<_> = self
+            .pieces(0..=u64::MAX)
+            .into_iter()
+            .map(|(range, value)| {
+                (
+                    value..=value + (range.end() - range.start()),
+                    *range.start(),
+                )
+            })
+            .collect();
+        pieces.sort_unstable_by_key(|(range, _)| *range.start());
+        if pieces
+            .windows(2)
+            .any(|pair| pair[0].0.end() >= pair[1].0.start())
+        {
+            let message = format!(
+                "el mapa {}-to-{} no se puede invertir",
+                self.source, self.destination
+            );
+            return Err(Error::solve(Day05::DAY, message));
+        }
+        Ok(RangeMap::from_pieces(self.destination, self.source, pieces))
+    }
+}
+
+impl fmt::Display for RangeMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.source, self.destination)?;
+        for (range, value) in &self.data {
+            let largo = u128::from(range.end() - range.start()) + 1;
+            writeln!(f, "{} {} {}", value, range.start(), largo)?;
+        }
+        Ok(())
+    }
+}
+
+/// The almanac: the seeds to plant and the maps between categories.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Almanac<'a> {
+    seeds: Vec<u64>,
+    range_maps: Vec<RangeMap<'a>>,
+}
+
+impl<'a> Almanac<'a> {
+    /// The numbers after `seeds:`.
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// The seeds read as `start length` pairs, an error for an odd count, an
+    /// empty range or one that doesn't fit in a `u64`.
+    pub fn seed_ranges(&self) -> Result<Vec<RangeInclusive<u64>>, Error> {
+        if !self.seeds.len().is_multiple_of(2) {
+            let error = Error::solve(Day05::DAY, "las semillas tienen que ir de a pares");
+            return Err(error.at_line(1));
+        }
+        let mut ranges = Vec::new();
+        for pair in self.seeds.chunks(2) {
+            let range = rango(pair[0], pair[1]).ok_or_else(|| {
+                let message = format!("rango de semillas invalido: {} {}", pair[0], pair[1]);
+                Error::solve(Day05::DAY, message).at_line(1)
+            })?;
+            ranges.push(range);
+        }
+        Ok(ranges)
+    }
+
+    /// The maps, in the order of the almanac.
+    pub fn range_maps(&self) -> &[RangeMap<'a>] {
+        &self.range_maps
+    }
+}
+
+impl fmt::Display for Almanac<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seeds = self.seeds.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let range_maps = self
+            .range_maps
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "seeds: {}\n\n{}", seeds.join(" "), range_maps.join("\n"))
+    }
+}
+
+//seeds: 79 14 55 13
+fn seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(tag("seeds: "), separated_list1(space1, complete::u64))(input)
+}
+
+//seed-to-soil map:
+fn range_map_title(input: &str) -> IResult<&str, (&str, &str)> {
+    terminated(
+        separated_pair(alpha1, tag("-to-"), alpha1),
+        pair(space1, tag("map:")),
+    )(input)
+}
+
+// un rango vacio no tiene ultimo elemento
+fn largo(input: &str) -> IResult<&str, u64> {
+    verify(complete::u64, |&largo| largo > 0)(input)
+}
+
+// el ultimo elemento tiene que entrar en un u64
+fn rango(min: u64, largo: u64) -> Option<RangeInclusive<u64>> {
+    Some(min..=min.checked_add(largo.checked_sub(1)?)?)
+}
+
+//50 98 2
+fn inclusive_range_value(input: &str) -> IResult<&str, (RangeInclusive<u64>, u64)> {
+    map_opt(
+        tuple((
+            terminated(complete::u64, space1),
+            terminated(complete::u64, space1),
+            terminated(largo, alt((line_ending, eof))),
+        )),
+        |(valor, min, largo)| {
+            rango(valor, largo)?;
+            Some((rango(min, largo)?, valor))
+        },
+    )(input)
+}
+
+// los rangos de un mapa no se pueden superponer
+fn range_map(input: &str) -> IResult<&str, RangeMap<'_>> {
+    let (mut input, (source, destination)) = terminated(range_map_title, line_ending)(input)?;
+    let mut range_map = RangeMap::new(source, destination);
+    let mut first = true;
+    loop {
+        let (rest, (range, value)) = match inclusive_range_value(input) {
+            Ok(parsed) => parsed,
+            Err(_) if !first => return Ok((input, range_map)),
+            Err(e) => return Err(e),
+        };
+        if !range_map.insert(range, value) {
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        input = rest;
+        first = false;
+    }
+}
+
+fn almanac(input: &str) -> IResult<&str, Almanac<'_>> {
+    let (input, (seeds, range_maps)) = separated_pair(
+        seeds,
+        pair(line_ending, line_ending),
+        separated_list1(line_ending, range_map),
+    )(input)?;
+    Ok((input, Almanac { seeds, range_maps }))
+}
+
+/// Parses the almanac and writes it back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    Ok(Day05::parse(input)?.to_string())
+}
+
+/// The [`Solution`] for day 5, the input is the almanac.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = Almanac<'a>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Almanac<'_>, Error> {
+        let almanac = parse::all(Self::DAY, input, almanac)?;
+        almanac.check(input)?;
+        Ok(almanac)
+    }
+
+    // the lowest location along any path
+    fn part1(almanac: &Almanac) -> Result<u64, Error> {
+        let chains = almanac.chains(SEED, LOCATION)?;
+        almanac
+            .seeds
+            .iter()
+            .flat_map(|&seed| chains.iter().map(move |chain| chain.get(seed)))
+            .min()
+            .ok_or_else(|| Error::solve(Self::DAY, "no hay semillas"))
+    }
+
+    // the seeds are `start length` ranges
+    fn part2(almanac: &Almanac) -> Result<u64, Error> {
+        let ranges = almanac.seed_ranges()?;
+        let chains = almanac.chains(SEED, LOCATION)?;
+        ranges
+            .into_iter()
+            .flat_map(|range| {
+                chains
+                    .iter()
+                    .flat_map(move |chain| chain.get_range(range.clone()))
+            })
+            .map(|range| *range.start())
+            .min()
+            .ok_or_else(|| Error::solve(Self::DAY, "no hay semillas"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_seeds() {
+        assert_eq!(seeds("seeds: 79 14 55 13"), Ok(("", vec![79, 14, 55, 13])));
+        let almanac = Day05::parse(INPUT).unwrap();
+        assert_eq!(almanac.seed_ranges(), Ok(vec![79..=92, 55..=67]));
+    }
+
+    #[test]
+    fn test_range_map_title() {
+        assert_eq!(
+            range_map_title("seed-to-soil map:"),
+            Ok(("", ("seed", "soil")))
+        );
+    }
+
+    #[test]
+    fn test_inclusive_range_value() {
+        assert_eq!(inclusive_range_value("50 98 2\n"), Ok(("", (98..=99, 50))));
+    }
+
+    #[test]
+    fn test_range_map() {
+        let mut range_map = RangeMap::new("seed", "soil");
+        assert!(range_map.insert(10..=19, 100));
+        assert!(range_map.insert(0..=4, 50));
+        assert!(!range_map.insert(15..=24, 0));
+        assert!(!range_map.insert(30..=31, u64::MAX));
+        assert_eq!(range_map.get(3), 53);
+        assert_eq!(range_map.get(7), 7);
+        assert_eq!(range_map.get(19), 109);
+        assert_eq!(range_map.get(20), 20);
+        // a range that starts right at the last number
+        assert_eq!(range_map.get_range(5..=10), vec![5..=9, 100..=100]);
+        assert_eq!(
+            range_map.get_range(3..=25),
+            vec![53..=54, 5..=9, 100..=109, 20..=25]
+        );
+        assert_eq!(
+            range_map.to_string(),
+            "seed-to-soil map:\n50 0 5\n100 10 10\n"
+        );
+    }
+
+    #[test]
+    fn test_compose() {
+        let almanac = Day05::parse(INPUT).unwrap();
+        let chain = almanac.chains(SEED, LOCATION).unwrap().remove(0);
+        assert_eq!((chain.source(), chain.destination()), ("seed", "location"));
+        for seed in 0..200 {
+            assert_eq!(
+                Ok(vec![chain.get(seed)]),
+                almanac.follow(seed, SEED, LOCATION)
+            );
+        }
+        let (soil, fertilizer) = (&almanac.range_maps()[0], &almanac.range_maps()[1]);
+        assert!(fertilizer.compose(soil).is_err());
+        assert_eq!(
+            soil.compose(&RangeMap::new("soil", "soil")),
+            Ok(soil.clone())
+        );
+    }
+
+    #[test]
+    fn test_invert() {
+        let almanac = Day05::parse(INPUT).unwrap();
+        let back = almanac.chains(SEED, LOCATION).unwrap()[0].invert().unwrap();
+        assert_eq!((back.source(), back.destination()), ("location", "seed"));
+        assert_eq!(back.get(46), 82);
+        for location in 0..200 {
+            let seed = back.get(location);
+            assert_eq!(almanac.follow(seed, SEED, LOCATION), Ok(vec![location]));
+        }
+        let soil = &almanac.range_maps()[0];
+        assert_eq!(soil.invert().unwrap().invert(), Ok(soil.clone()));
+
+        let mut range_map = RangeMap::new("seed", "soil");
+        range_map.insert(0..=1, 5);
+        assert!(range_map.invert().is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        let almanac = Day05::parse(INPUT).unwrap();
+        assert_eq!(Day05::part1(&almanac), Ok(35));
+        assert_eq!(almanac.follow(79, SEED, LOCATION), Ok(vec![82]));
+    }
+
+    #[test]
+    fn test_part2() {
+        let almanac = Day05::parse(INPUT).unwrap();
+        assert_eq!(Day05::part2(&almanac), Ok(46));
+    }
+
+    #[test]
+    fn test_rango_vacio() {
+        let error = Day05::parse("seeds: 79\n\nseed-to-soil map:\n50 98 0\n").unwrap_err();
+        assert_eq!(error.line, Some(4));
+        let almanac = Day05::parse("seeds: 79\n\nseed-to-soil map:\n50 98 1\n").unwrap();
+        assert_eq!(Day05::part2(&almanac).unwrap_err().line, Some(1));
+        let almanac = Day05::parse("seeds: 79 0\n\nseed-to-soil map:\n50 98 1\n").unwrap();
+        assert!(Day05::part2(&almanac).is_err());
+    }
+
+    #[test]
+    fn test_solapados() {
+        let input = "seeds: 79\n\nseed-to-soil map:\n50 98 2\n10 90 9\n";
+        assert_eq!(Day05::parse(input).unwrap_err().line, Some(5));
+        let input = "seeds: 79\n\nseed-to-soil map:\n18446744073709551615 98 2\n";
+        assert_eq!(Day05::parse(input).unwrap_err().line, Some(4));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "seeds: 79  14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-water map:\n0 15 37";
+        let written = rewrite(input).unwrap();
+        let expected =
+            "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-water map:\n0 15 37\n";
+        assert_eq!(written, expected);
+        parse::round_trip(input, rewrite);
+        assert!(rewrite("seeds: 1 2\n\nseed-to-soil map:\n0 18446744073709551615 2").is_err());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day05>(include_str!("test_input.txt"));
+    }
+}
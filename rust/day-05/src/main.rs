@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_05::Day05;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let almanac = Day05::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day05::part1(&almanac)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day05::part2(&almanac)?,
+        now.elapsed()
+    );
+    Ok(())
+}
@@ -0,0 +1,324 @@
+//! Day 6: Wait For It. How long to hold the button of a toy boat to win the
+//! race, solved with the roots of a quadratic.
+
+#![warn(missing_docs)]
+
+use std::ops::RangeInclusive;
+
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{digit1, line_ending, space1},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+use num_bigint::BigUint;
+
+use aoc_core::{parse, Error, Solution};
+
+/// A race, how long it lasts and the record distance to beat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    /// Milliseconds the race lasts.
+    pub time: u128,
+    /// Millimeters of the record.
+    pub distance: u128,
+}
+
+/// How a distance has to compare with the record to win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Comparison {
+    /// Further than the record, what the puzzle asks.
+    #[default]
+    Strict,
+    /// At least as far as the record.
+    NonStrict,
+}
+
+impl Race {
+    /// How far the boat goes holding the button `held` milliseconds. Every
+    /// millisecond held adds a millimeter per millisecond to the speed.
+    pub fn travelled(&self, held: u128) -> BigUint {
+        let held = held.min(self.time);
+        BigUint::from(held) * (self.time - held)
+    }
+
+    fn wins(&self, held: u128, comparison: Comparison) -> bool {
+        let distance = self.travelled(held);
+        let record = BigUint::from(self.distance);
+        match comparison {
+            Comparison::Strict => distance > record,
+            Comparison::NonStrict => distance >= record,
+        }
+    }
+
+    /// The milliseconds to hold the button that win, `None` if none does.
+    /// The distance is `held * (time - held)`, so they are the ones between
+    /// the roots of `held² - time * held + distance`, found with an integer
+    /// square root and then moved to the first one that wins.
+    pub fn winning(&self, comparison: Comparison) -> Option<RangeInclusive<u128>> {
+        let time = BigUint::from(self.time);
+        let square = &time * &time;
+        let record = BigUint::from(self.distance) << 2;
+        if square < record {
+            return None;
+        }
+        // the root is off by less than one, the division by two rounds down
+        let first = (time - (square - record).sqrt()) >> 1;
+        let mut first = u128::try_from(first).expect("no es mas que el tiempo");
+        while first > 0 && self.wins(first - 1, comparison) {
+            first -= 1;
+        }
+        // the distance is largest at half the time
+        let middle = self.time / 2;
+        while first <= middle && !self.wins(first, comparison) {
+            first += 1;
+        }
+        (first <= middle).then(|| first..=self.time - first)
+    }
+
+    /// How many ways of holding the button win, an error if they don't fit
+    /// in a `u128`.
+    pub fn ways_to_win(&self, comparison: Comparison) -> Result<u128, Error> {
+        match self.winning(comparison) {
+            Some(range) => (range.end() - range.start())
+                .checked_add(1)
+                .ok_or_else(|| Error::solve(Day06::DAY, "demasiadas maneras de ganar")),
+            None => Ok(0),
+        }
+    }
+}
+
+// cuantas maneras de ganar hay en total
+fn maneras(races: &[Race]) -> Result<u128, Error> {
+    races.iter().try_fold(1u128, |total, race| {
+        total
+            .checked_mul(race.ways_to_win(Comparison::Strict)?)
+            .ok_or_else(|| Error::solve(Day06::DAY, "demasiadas maneras de ganar"))
+    })
+}
+
+/// The sheet of paper with the races, its numbers as they are written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sheet<'a> {
+    input: &'a str,
+    times: Vec<&'a str>,
+    distances: Vec<&'a str>,
+}
+
+// un error de lectura en el primer digito, que es parte de la hoja
+fn numero(sheet: &Sheet, first: &str, digits: &str) -> Result<u128, Error> {
+    digits.parse().map_err(|_| {
+        let message = format!("numero demasiado grande: {}", digits);
+        Error::parse(Day06::DAY, message).at_rest(sheet.input, first)
+    })
+}
+
+impl Sheet<'_> {
+    /// One race for each column of the sheet, an error pointing at any
+    /// number that doesn't fit in a `u128`.
+    pub fn races(&self) -> Result<Vec<Race>, Error> {
+        self.times
+            .iter()
+            .zip(&self.distances)
+            .map(|(time, distance)| {
+                Ok(Race {
+                    time: numero(self, time, time)?,
+                    distance: numero(self, distance, distance)?,
+                })
+            })
+            .collect()
+    }
+
+    /// The one race the sheet is about, ignoring the spaces between the
+    /// digits. A number too big points at its first digit.
+    pub fn single_race(&self) -> Result<Race, Error> {
+        Ok(Race {
+            time: numero(self, self.times[0], &self.times.concat())?,
+            distance: numero(self, self.distances[0], &self.distances.concat())?,
+        })
+    }
+}
+
+//Time:        56     71     79     99
+fn numeros(input: &str) -> IResult<&str, Vec<&str>> {
+    preceded(is_not("0123456789"), separated_list1(space1, digit1))(input)
+}
+
+//Time:        56     71     79     99
+//Distance:   334   1135   1350   2430
+fn sheet(input: &str) -> IResult<&str, Sheet<'_>> {
+    let (rest, (times, distances)) = separated_pair(numeros, line_ending, numeros)(input)?;
+    let sheet = Sheet {
+        input,
+        times,
+        distances,
+    };
+    Ok((rest, sheet))
+}
+
+/// Parses the races and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let sheet = Day06::parse(input)?;
+    Ok(format!(
+        "Time: {}\nDistance: {}\n",
+        sheet.times.join(" "),
+        sheet.distances.join(" ")
+    ))
+}
+
+/// The [`Solution`] for day 6, the input is the sheet with the races.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = Sheet<'a>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Sheet<'_>, Error> {
+        let sheet = parse::all(Self::DAY, input, sheet)?;
+        if sheet.times.len() != sheet.distances.len() {
+            return Err(Error::parse(
+                Self::DAY,
+                "la cantidad de tiempos y distancias no coincide",
+            ));
+        }
+        Ok(sheet)
+    }
+
+    fn part1(sheet: &Sheet) -> Result<u128, Error> {
+        maneras(&sheet.races()?)
+    }
+
+    fn part2(sheet: &Sheet) -> Result<u128, Error> {
+        maneras(&[sheet.single_race()?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let sheet = Day06::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(Day06::part1(&sheet).unwrap(), 288);
+    }
+
+    #[test]
+    fn test_part2() {
+        let sheet = Day06::parse(include_str!("input_test.txt")).unwrap();
+        let race = Race {
+            time: 71530,
+            distance: 940200,
+        };
+        assert_eq!(sheet.single_race(), Ok(race));
+        assert_eq!(Day06::part2(&sheet).unwrap(), 71503);
+    }
+
+    // every way to hold the button, one by one
+    fn brute_force(race: &Race, comparison: Comparison) -> Vec<u128> {
+        (0..=race.time)
+            .filter(|&held| race.wins(held, comparison))
+            .collect()
+    }
+
+    #[test]
+    fn test_winning() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        assert_eq!(race.winning(Comparison::Strict), Some(2..=5));
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        assert_eq!(race.winning(Comparison::Strict), Some(11..=19));
+        assert_eq!(race.winning(Comparison::NonStrict), Some(10..=20));
+
+        for time in 0..40 {
+            for distance in 0..420 {
+                let race = Race { time, distance };
+                for comparison in [Comparison::Strict, Comparison::NonStrict] {
+                    let expected = brute_force(&race, comparison);
+                    let winning = race.winning(comparison);
+                    assert_eq!(winning.map_or(vec![], Vec::from_iter), expected);
+                    assert_eq!(race.ways_to_win(comparison), Ok(expected.len() as u128));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_carreras_largas() {
+        let race = Race {
+            time: u128::MAX,
+            distance: u128::MAX,
+        };
+        assert_eq!(race.winning(Comparison::Strict), Some(2..=u128::MAX - 2));
+        let race = Race {
+            time: u128::MAX,
+            distance: 0,
+        };
+        assert!(race.ways_to_win(Comparison::NonStrict).is_err());
+        assert_eq!(maneras(&[race]), Ok(u128::MAX - 1));
+        assert!(maneras(&[race, race]).is_err());
+        let race = Race {
+            time: 20_000_000_000_000_000_000,
+            distance: 99_999_999_999_999_999_999_999_999_999_999_999_999,
+        };
+        let middle = 10_000_000_000_000_000_000;
+        assert_eq!(race.winning(Comparison::Strict), Some(middle..=middle));
+        let race = Race {
+            distance: race.distance + 1,
+            ..race
+        };
+        assert_eq!(race.winning(Comparison::Strict), None);
+        assert_eq!(race.winning(Comparison::NonStrict), Some(middle..=middle));
+
+        let sheet = Day06::parse("Time: 9999999999 9999999999\nDistance: 1 1").unwrap();
+        assert_eq!(Day06::part1(&sheet), Ok(9_999_999_998 * 9_999_999_998));
+        assert_eq!(Day06::part2(&sheet), Ok(99_999_999_999_999_999_998));
+    }
+
+    #[test]
+    fn test_errores() {
+        assert!(Day06::parse("Time: 7 15\nDistance: 9").is_err());
+        let error = Day06::parse("Time: 7 15\nDistance: 9 x").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(13)));
+        let sheet = Day06::parse("Time: 7 9999999999999999999\nDistance: 9 40").unwrap();
+        assert!(Day06::part2(&sheet).is_ok());
+        let input = format!("Time: 7 {}\nDistance: 9 40", "9".repeat(39));
+        let sheet = Day06::parse(&input).unwrap();
+        let error = sheet.races().unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Parse);
+        assert_eq!((error.line, error.column), (Some(1), Some(9)));
+        assert!(Day06::part1(&sheet).is_err());
+        let error = Day06::part2(&sheet).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(7)));
+        let input = format!("Time: 7 40\nDistance:  {} 9", "9".repeat(38));
+        let sheet = Day06::parse(&input).unwrap();
+        assert!(sheet.races().is_ok());
+        let error = Day06::part2(&sheet).unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Parse);
+        assert_eq!((error.line, error.column), (Some(2), Some(12)));
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        let written = rewrite(input).unwrap();
+        assert_eq!(written, "Time: 7 15 30\nDistance: 9 40 200\n");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day06>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_06::Day06;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let sheet = Day06::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day06::part1(&sheet)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day06::part2(&sheet)?,
+        now.elapsed()
+    );
+    Ok(())
+}
@@ -0,0 +1,193 @@
+//! Day 7: Camel Cards. Ranking poker-like hands.
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use aoc_core::{parse, Error, Solution};
+
+/// A hand of five cards and its bid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: u32,
+}
+
+/// Value of `card` for breaking ties, with `jokers` a `J` is the weakest.
+fn card_value(card: char, jokers: bool) -> u32 {
+    match card {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' if jokers => 1,
+        'J' => 11,
+        'T' => 10,
+        _ => card as u32 - '0' as u32,
+    }
+}
+
+impl Hand {
+    /// A hand of `cards`, which must be five of `AKQJT98765432`.
+    pub fn new(cards: &str, bid: u32) -> Result<Hand, String> {
+        let cards = cards
+            .chars()
+            .map(|c| match c {
+                'A' | 'K' | 'Q' | 'J' | 'T' | '2'..='9' => Ok(c),
+                _ => Err(format!("carta invalida: {}", c)),
+            })
+            .collect::<Result<Vec<char>, String>>()?;
+        if cards.len() != 5 {
+            return Err(format!("una mano tiene 5 cartas, no {}", cards.len()));
+        }
+        Ok(Hand { cards, bid })
+    }
+
+    /// The cards in the order they were dealt.
+    pub fn cards(&self) -> &[char] {
+        &self.cards
+    }
+
+    /// What the hand wins for each rank.
+    pub fn bid(&self) -> u32 {
+        self.bid
+    }
+
+    /// The type of the hand, from 1 for high card to 7 for five of a kind.
+    /// With `jokers` every `J` counts as the card there are most of.
+    pub fn kind(&self, jokers: bool) -> u32 {
+        let mut map = HashMap::new();
+        self.cards.iter().for_each(|card| {
+            let counter = map.entry(*card).or_insert(0);
+            *counter += 1;
+        });
+
+        if let Some(&cant_jokers) = map.get(&'J').filter(|_| jokers) {
+            if cant_jokers < 5 {
+                map.remove(&'J');
+                let mut key_max = 'x';
+                let mut cant_max = 0;
+                for (key, value) in map.iter() {
+                    if *value > cant_max {
+                        key_max = *key;
+                        cant_max = *value;
+                    }
+                }
+                map.entry(key_max).and_modify(|e| *e += cant_jokers);
+            }
+        }
+
+        match map.len() {
+            1 => 7, //Five of a kind
+            2 => {
+                if map.values().any(|&value| value == 4) {
+                    6 //Four of a kind
+                } else {
+                    5 //Full house
+                }
+            }
+            3 => {
+                if map.values().any(|&value| value == 3) {
+                    4 //Three of a kind
+                } else {
+                    3 //Two pair
+                }
+            }
+            4 => 2, //One pair
+            _ => 1, //High card
+        }
+    }
+
+    /// What hands are sorted by: the type and then card by card.
+    fn strength(&self, jokers: bool) -> (u32, Vec<u32>) {
+        let values = self.cards.iter().map(|&c| card_value(c, jokers)).collect();
+        (self.kind(jokers), values)
+    }
+}
+
+fn parse_hand(line: &str) -> Result<Hand, String> {
+    let (cards_str, bid_str) = line.split_once(' ').ok_or("se esperaba `cartas apuesta`")?;
+
+    let bid = bid_str
+        .parse::<u32>()
+        .map_err(|e| format!("apuesta invalida: {}", e))?;
+    Hand::new(cards_str, bid)
+}
+
+/// Total winnings, each bid times the rank of its hand from the weakest. With
+/// `jokers` a `J` is a joker.
+pub fn total_winnings(hands: &[Hand], jokers: bool) -> u32 {
+    let mut hands = hands.iter().collect::<Vec<_>>();
+    hands.sort_by_cached_key(|hand| hand.strength(jokers));
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| (i + 1) as u32 * hand.bid)
+        .sum()
+}
+
+/// The [`Solution`] for day 7, the input is the list of hands.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<Hand>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, Error> {
+        parse::lines_with(Self::DAY, input, parse_hand)
+    }
+
+    fn part1(hands: &Vec<Hand>) -> Result<u32, Error> {
+        Ok(total_winnings(hands, false))
+    }
+
+    fn part2(hands: &Vec<Hand>) -> Result<u32, Error> {
+        Ok(total_winnings(hands, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let hands = Day07::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(Day07::part1(&hands).unwrap(), 6440);
+    }
+
+    #[test]
+    fn test_part2() {
+        let hands = Day07::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(Day07::part2(&hands).unwrap(), 5905);
+    }
+
+    #[test]
+    fn test_kind() {
+        let kind = |cards, jokers| Hand::new(cards, 0).unwrap().kind(jokers);
+        assert_eq!(kind("32T3K", false), 2);
+        assert_eq!(kind("KTJJT", false), 3);
+        assert_eq!(kind("KTJJT", true), 6);
+        assert_eq!(kind("JJJJJ", true), 7);
+        assert_eq!(kind("23456", true), 1);
+    }
+
+    #[test]
+    fn test_manos_invalidas() {
+        for input in [
+            "32T3K 765\n32T3 1",
+            "32T3K 765\n32T3X 1",
+            "32T3K 765\n32T3K",
+        ] {
+            assert_eq!(Day07::parse(input).unwrap_err().line, Some(2));
+        }
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day07>(include_str!("input_test.txt"));
+    }
+}
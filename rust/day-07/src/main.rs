@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_07::Day07;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let hands = Day07::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day07::part1(&hands)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day07::part2(&hands)?,
+        now.elapsed()
+    );
+    Ok(())
+}
@@ -0,0 +1,328 @@
+//! Day 8: Haunted Wasteland. Following left and right instructions through a
+//! network of nodes.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alphanumeric1, line_ending, multispace1},
+    combinator::{eof, map_res},
+    multi::{fold_many1, many1},
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Parser,
+};
+
+use aoc_core::{math::lcm, parse, Error, Solution};
+
+#[cfg(test)]
+mod reference;
+
+/// Which way to go from a node.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    /// `L`, the first node of the pair.
+    Left,
+    /// `R`, the second node of the pair.
+    Right,
+}
+
+/// A node of the network, named by three letters or digits.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Node {
+    first: char,
+    second: char,
+    third: char,
+}
+
+impl Node {
+    /// Whether a ghost stops here, its name ends in `Z`.
+    pub fn is_end_node(&self) -> bool {
+        self.third == 'Z'
+    }
+
+    /// Whether a ghost starts here, its name ends in `A`.
+    pub fn is_start_node(&self) -> bool {
+        self.third == 'A'
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.first, self.second, self.third)
+    }
+}
+
+impl FromStr for Node {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let first = chars.next().ok_or("no existe primer caracter")?;
+        let second = chars.next().ok_or("no existe segundo caracter")?;
+        let third = chars.next().ok_or("no existe tercer caracter")?;
+        Ok(Node {
+            first,
+            second,
+            third,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Instructions {
+    instructions: Vec<Direction>,
+}
+
+impl Instructions {
+    fn cycle(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.instructions.iter().copied().cycle()
+    }
+}
+
+/// The map: the instructions to repeat and where each node leads.
+#[derive(Debug)]
+pub struct PuzzleMap {
+    instructions: Instructions,
+    nodes: HashMap<Node, (Node, Node)>,
+}
+
+impl fmt::Display for PuzzleMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for direction in &self.instructions.instructions {
+            match direction {
+                Direction::Left => write!(f, "L")?,
+                Direction::Right => write!(f, "R")?,
+            }
+        }
+        writeln!(f, "\n")?;
+        let mut nodes = self.nodes.iter().collect::<Vec<_>>();
+        nodes.sort();
+        for (node, (left, right)) in nodes {
+            writeln!(f, "{} = ({}, {})", node, left, right)?;
+        }
+        Ok(())
+    }
+}
+
+impl PuzzleMap {
+    /// The instructions, followed again from the start when they run out.
+    pub fn instructions(&self) -> &[Direction] {
+        &self.instructions.instructions
+    }
+
+    /// The left and right node of every node.
+    pub fn nodes(&self) -> &HashMap<Node, (Node, Node)> {
+        &self.nodes
+    }
+
+    //steps to the first node that is_end accepts, starting at the instruction desde
+    fn navigate(
+        &self,
+        inicio: &Node,
+        desde: u64,
+        is_end: impl Fn(&Node) -> bool,
+    ) -> Result<(u64, Node), String> {
+        let mut current_node = *inicio;
+        let largo = self.instructions.instructions.len();
+        let salto = desde as usize % largo.max(1);
+        //past every node at every instruction the walk is going round in circles
+        let limite = self.nodes.len() * largo + 1;
+        let pasos = self.instructions.cycle().skip(salto).take(limite);
+        for (paso, direction) in pasos.enumerate() {
+            let (left, right) = self
+                .nodes
+                .get(&current_node)
+                .ok_or_else(|| format!("no existe el nodo {}", current_node))?;
+            match direction {
+                Direction::Left => {
+                    current_node = *left;
+                }
+                Direction::Right => {
+                    current_node = *right;
+                }
+            }
+            if is_end(&current_node) {
+                return Ok((paso as u64 + 1, current_node));
+            }
+        }
+        if largo == 0 {
+            return Err("no hay instrucciones".to_owned());
+        }
+        Err(format!("desde {} nunca se llega a un nodo final", inicio))
+    }
+
+    /// Steps from `AAA` to `ZZZ`.
+    pub fn steps_to_zzz(&self) -> Result<u64, String> {
+        let (aaa, zzz) = ("AAA".parse()?, "ZZZ".parse::<Node>()?);
+        Ok(self.navigate(&aaa, 0, |node| *node == zzz)?.0)
+    }
+
+    //the lcm only works if each ghost then goes around a loop as long as the
+    //way there, every round ending on the same node until the instructions
+    //line up again with the end of the first round
+    fn ghost_cycle(&self, inicio: &Node) -> Result<u64, String> {
+        let (pasos, fin) = self.navigate(inicio, 0, Node::is_end_node)?;
+        let largo = self.instructions.instructions.len() as u64;
+        let mut desde = pasos;
+        loop {
+            let (vuelta, otro_fin) = self.navigate(&fin, desde, Node::is_end_node)?;
+            if vuelta != pasos || otro_fin != fin {
+                return Err(format!(
+                    "el fantasma que sale de {} no repite su camino cada {} pasos, el mcm no sirve",
+                    inicio, pasos
+                ));
+            }
+            desde += pasos;
+            if desde % largo == pasos % largo {
+                return Ok(pasos);
+            }
+        }
+    }
+
+    /// Steps until every ghost, one from each start node, is on an end node
+    /// at the same time. An error when the ghosts don't go round in loops
+    /// the LCM of their lengths can be taken of, or when the LCM doesn't fit
+    /// in a `u64`.
+    pub fn ghost_navigate(&self) -> Result<u64, String> {
+        self.nodes
+            .keys()
+            .filter(|node| node.is_start_node())
+            .map(|node| self.ghost_cycle(node))
+            .try_fold(1, |acc, cant| {
+                lcm(acc, cant?).ok_or_else(|| "el mcm de los ciclos no entra en un u64".to_string())
+            })
+    }
+}
+
+fn node_parser(input: &str) -> IResult<&str, Node> {
+    map_res(alphanumeric1, str::parse)(input)
+}
+
+fn instructions_parser(input: &str) -> IResult<&str, Instructions> {
+    let (input, instructions) = many1(alt((
+        complete::char('R').map(|_| Direction::Right),
+        complete::char('L').map(|_| Direction::Left),
+    )))(input)?;
+    Ok((input, Instructions { instructions }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, PuzzleMap> {
+    let (input, instructions) = instructions_parser(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, nodes) = fold_many1(
+        terminated(
+            separated_pair(
+                node_parser,
+                tag(" = "),
+                delimited(
+                    complete::char('('),
+                    separated_pair(node_parser, tag(", "), node_parser),
+                    complete::char(')'),
+                ),
+            ),
+            alt((line_ending, eof)),
+        ),
+        HashMap::new,
+        |mut acc: HashMap<Node, (Node, Node)>, (key, value)| {
+            acc.insert(key, value);
+            acc
+        },
+    )(input)?;
+    Ok((
+        input,
+        PuzzleMap {
+            instructions,
+            nodes,
+        },
+    ))
+}
+
+/// Parses the map and writes it back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    Ok(Day08::parse(input)?.to_string())
+}
+
+/// The [`Solution`] for day 8, the input is the map.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = PuzzleMap;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<PuzzleMap, Error> {
+        parse::all(Self::DAY, input, parse_input)
+    }
+
+    fn part1(map: &PuzzleMap) -> Result<u64, Error> {
+        map.steps_to_zzz().map_err(|e| Error::solve(Self::DAY, e))
+    }
+
+    fn part2(map: &PuzzleMap) -> Result<u64, Error> {
+        map.ghost_navigate().map_err(|e| Error::solve(Self::DAY, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_camino1() {
+        let map = Day08::parse(include_str!("input_test1.txt")).unwrap();
+        assert_eq!(Day08::part1(&map).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_camino2() {
+        let map = Day08::parse(include_str!("input_test2.txt")).unwrap();
+        assert_eq!(Day08::part1(&map).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_fantasmas() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let map = Day08::parse(input).unwrap();
+        assert_eq!(Day08::part2(&map).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_nodo_inexistente() {
+        let map = Day08::parse("L\n\nAAA = (BBB, BBB)").unwrap();
+        let error = Day08::part1(&map).unwrap_err();
+        assert_eq!(error.stage, aoc_core::Stage::Solve);
+        assert_eq!(error.message, "no existe el nodo BBB");
+        let error = Day08::parse("L\n\nAAA = (BBB, BBB)\nBB = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(4), Some(1)));
+        let map = Day08::parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert!(Day08::part1(&map).is_err());
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "LLR\n\nBBB = (AAA, ZZZ)\nAAAA = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)";
+        let written = rewrite(input).unwrap();
+        let expected = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(written, expected);
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day08>(include_str!("input_test1.txt"));
+    }
+}
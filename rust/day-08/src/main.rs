@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_08::Day08;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let map = Day08::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day08::part1(&map)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day08::part2(&map)?,
+        now.elapsed()
+    );
+    Ok(())
+}
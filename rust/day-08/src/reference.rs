@@ -0,0 +1,102 @@
+//! Walks every ghost at the same time until they all stand on an end node,
+//! to check the LCM of part 2 against.
+
+use aoc_core::Solution;
+use aoc_gen::day08::{generate, Config};
+use proptest::prelude::*;
+
+use crate::{Day08, Direction, PuzzleMap};
+
+/// Steps until every ghost is on a `..Z` node, if that happens within
+/// `limit` steps.
+fn lockstep(map: &PuzzleMap, limit: u64) -> Option<u64> {
+    let mut ghosts = map
+        .nodes
+        .keys()
+        .filter(|node| node.is_start_node())
+        .copied()
+        .collect::<Vec<_>>();
+    let directions = map.instructions.instructions.iter().cycle();
+    for (paso, direction) in directions.take(limit as usize).enumerate() {
+        for ghost in ghosts.iter_mut() {
+            let (left, right) = map.nodes.get(ghost)?;
+            *ghost = match direction {
+                Direction::Left => *left,
+                Direction::Right => *right,
+            };
+        }
+        if ghosts.iter().all(|ghost| ghost.is_end_node()) {
+            return Some(paso as u64 + 1);
+        }
+    }
+    None
+}
+
+fn map(input: &str) -> PuzzleMap {
+    Day08::parse(input).unwrap()
+}
+
+#[test]
+fn test_lockstep() {
+    let input = include_str!("input_test2.txt");
+    assert_eq!(lockstep(&map(input), 100), Some(6));
+}
+
+#[test]
+fn test_offset() {
+    // 11A reaches 11Z after one step and then every two, together at 3
+    let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n\
+                 22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)";
+    assert_eq!(lockstep(&map(input), 100), Some(3));
+    assert!(Day08::part2(&map(input)).is_err());
+}
+
+// Ghosts on a few nodes wired at random, the name says if a node is a
+// start, an end or neither.
+fn network() -> impl Strategy<Value = String> {
+    (2..8usize)
+        .prop_flat_map(|nodes| {
+            let kinds = prop::collection::vec(prop::sample::select(vec!['A', 'Z', 'B']), nodes);
+            let edges = prop::collection::vec((0..nodes, 0..nodes), nodes);
+            let instructions = prop::collection::vec(prop::bool::ANY, 1..6);
+            (kinds, edges, instructions)
+        })
+        .prop_map(|(kinds, edges, instructions)| {
+            let name = |i: usize| format!("{}{}", (b'C' + i as u8) as char, kinds[i]);
+            let mut input = instructions
+                .iter()
+                .map(|&left| if left { 'L' } else { 'R' })
+                .collect::<String>();
+            input.push_str("\n\n");
+            for (i, &(left, right)) in edges.iter().enumerate() {
+                input.push_str(&format!(
+                    "X{} = (X{}, X{})\n",
+                    name(i),
+                    name(left),
+                    name(right)
+                ));
+            }
+            input
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_generated(seed: u64, instructions in 1..6usize, ghosts in 1..4usize) {
+        let input = generate(&mut aoc_gen::rng(seed), &Config { instructions, ghosts });
+        let steps = Day08::part2(&map(&input)).unwrap();
+        prop_assert_eq!(lockstep(&map(&input), steps), Some(steps));
+    }
+
+    #[test]
+    fn test_any_network(input in network()) {
+        // most random networks break the assumptions of part 2, it has
+        // to say so instead of answering wrong
+        if let Ok(steps) = Day08::part2(&map(&input)) {
+            prop_assume!(steps <= 100_000);
+            prop_assert_eq!(lockstep(&map(&input), steps), Some(steps));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Day 9: Mirage Maintenance. Extrapolating sequences by their differences.
+
+#![warn(missing_docs)]
+
+use itertools::Itertools;
+
+use aoc_core::{parse, Error, Solution};
+
+fn numeros(line: &str) -> Result<Vec<i64>, String> {
+    line.split_whitespace()
+        .map(|num| num.parse::<i64>().map_err(|e| format!("{}: {}", num, e)))
+        .collect()
+}
+
+/// First and last value of the sequence and of each sequence of differences
+/// after it, until one is all zeros.
+fn bordes(sequence: &[i64]) -> Vec<(i64, i64)> {
+    let mut bordes = Vec::new();
+    let mut numbers = sequence.to_vec();
+    while let (Some(&first), Some(&last)) = (numbers.first(), numbers.last()) {
+        if numbers.iter().all(|num| *num == 0) {
+            break;
+        }
+        bordes.push((first, last));
+        numbers = numbers
+            .iter()
+            .tuple_windows()
+            .map(|(iz, der)| der - iz)
+            .collect();
+    }
+    bordes
+}
+
+/// The value that would come after the last one of `sequence`.
+pub fn next_value(sequence: &[i64]) -> i64 {
+    bordes(sequence).iter().map(|(_, last)| last).sum()
+}
+
+/// The value that would come before the first one of `sequence`.
+pub fn previous_value(sequence: &[i64]) -> i64 {
+    bordes(sequence)
+        .iter()
+        .rev()
+        .fold(0, |acc, (first, _)| first - acc)
+}
+
+/// The [`Solution`] for day 9, the input is one sequence per line.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+        parse::lines_with(Self::DAY, input, numeros)
+    }
+
+    fn part1(sequences: &Vec<Vec<i64>>) -> Result<i64, Error> {
+        Ok(sequences.iter().map(|s| next_value(s)).sum())
+    }
+
+    fn part2(sequences: &Vec<Vec<i64>>) -> Result<i64, Error> {
+        Ok(sequences.iter().map(|s| previous_value(s)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn test_part1() {
+        let sequences = Day09::parse(INPUT).unwrap();
+        assert_eq!(Day09::part1(&sequences).unwrap(), 114);
+    }
+
+    #[test]
+    fn test_part2() {
+        let sequences = Day09::parse(INPUT).unwrap();
+        assert_eq!(Day09::part2(&sequences).unwrap(), 2);
+        assert_eq!(previous_value(&[10, 13, 16, 21, 30, 45]), 5);
+    }
+
+    #[test]
+    fn test_numero_invalido() {
+        let error = Day09::parse("0 3 6\n1 x 3").unwrap_err();
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day09>(include_str!("input.txt"));
+    }
+}
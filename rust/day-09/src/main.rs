@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_09::Day09;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let sequences = Day09::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day09::part1(&sequences)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day09::part2(&sequences)?,
+        now.elapsed()
+    );
+    Ok(())
+}
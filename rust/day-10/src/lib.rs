@@ -0,0 +1,571 @@
+//! Day 10: Pipe Maze. The loop of pipes through the start and the tiles it
+//! encloses.
+
+#![warn(missing_docs)]
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Direction, Error, Point, Solution, Visualize,
+};
+
+/// A tile of the maze.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TypeNode {
+    /// `|`
+    NorthSouth,
+    /// `-`
+    EastWest,
+    /// `L`
+    NorthEast,
+    /// `J`
+    NorthWest,
+    /// `7`
+    SouthWest,
+    /// `F`
+    SouthEast,
+    /// `.`
+    Ground,
+    /// `S`, a pipe of unknown shape.
+    Start,
+}
+
+impl TypeNode {
+    /// The tile in box drawing characters.
+    pub fn glyph(&self) -> char {
+        match self {
+            TypeNode::NorthSouth => '│',
+            TypeNode::EastWest => '─',
+            TypeNode::NorthEast => '└',
+            TypeNode::NorthWest => '┘',
+            TypeNode::SouthWest => '┐',
+            TypeNode::SouthEast => '┌',
+            TypeNode::Ground => '.',
+            TypeNode::Start => 'S',
+        }
+    }
+}
+
+/// The pipe the start stands for, from the two loop tiles connected to it.
+fn start_equivalence(
+    map: &Grid<TypeNode>,
+    visitados: &Grid<bool>,
+    start: Point,
+) -> Result<TypeNode, Error> {
+    let north = start.step(Direction::North);
+    let south = start.step(Direction::South);
+    let east = start.step(Direction::East);
+    let west = start.step(Direction::West);
+    let conected_north = visitado(visitados, north)
+        && match map.get(north) {
+            Some(node) => {
+                *node == TypeNode::NorthSouth
+                    || *node == TypeNode::SouthEast
+                    || *node == TypeNode::SouthWest
+            }
+            None => false,
+        };
+    let conected_south = visitado(visitados, south)
+        && match map.get(south) {
+            Some(node) => {
+                *node == TypeNode::NorthSouth
+                    || *node == TypeNode::NorthEast
+                    || *node == TypeNode::NorthWest
+            }
+            None => false,
+        };
+    let conected_east = visitado(visitados, east)
+        && match map.get(east) {
+            Some(node) => {
+                *node == TypeNode::EastWest
+                    || *node == TypeNode::NorthWest
+                    || *node == TypeNode::SouthWest
+            }
+            None => false,
+        };
+    let conected_west = visitado(visitados, west)
+        && match map.get(west) {
+            Some(node) => {
+                *node == TypeNode::EastWest
+                    || *node == TypeNode::NorthEast
+                    || *node == TypeNode::SouthEast
+            }
+            None => false,
+        };
+    let case = (conected_north, conected_south, conected_east, conected_west);
+    Ok(match case {
+        (true, true, false, false) => TypeNode::NorthSouth,
+        (true, false, true, false) => TypeNode::NorthEast,
+        (true, false, false, true) => TypeNode::NorthWest,
+        (false, true, true, false) => TypeNode::SouthEast,
+        (false, true, false, true) => TypeNode::SouthWest,
+        (false, false, true, true) => TypeNode::EastWest,
+        _ => {
+            return Err(Error::solve(
+                Day10::DAY,
+                "no se puede llegar al punto de inicio",
+            ))
+        }
+    })
+}
+
+/// Whether the loop already went through `point`.
+fn visitado(visitados: &Grid<bool>, point: Point) -> bool {
+    visitados.get(point).copied().unwrap_or(false)
+}
+
+/// Outside the map counts as ground.
+fn tipo<'a>(map: &'a Grid<TypeNode>, point: &Point) -> &'a TypeNode {
+    map.get(*point).unwrap_or(&TypeNode::Ground)
+}
+
+/// Tiles on the loop and tiles it encloses.
+pub struct Enclosed {
+    /// `true` for the tiles of the loop.
+    pub pipes: Grid<bool>,
+    /// `true` for the tiles inside the loop.
+    pub inside: Grid<bool>,
+}
+
+/// The maze of pipes and where the animal started.
+#[derive(Debug)]
+pub struct Maze {
+    map: Grid<TypeNode>,
+    start: Point,
+}
+
+impl Maze {
+    /// Parses the tiles, there has to be one start.
+    pub fn new(input: &str) -> Result<Maze, Error> {
+        let grid = Grid::parse(Day10::DAY, input, "|-LJ7F.S")?;
+        let map = grid.map(|c| match c {
+            '|' => TypeNode::NorthSouth,
+            '-' => TypeNode::EastWest,
+            'L' => TypeNode::NorthEast,
+            'J' => TypeNode::NorthWest,
+            '7' => TypeNode::SouthWest,
+            'F' => TypeNode::SouthEast,
+            '.' => TypeNode::Ground,
+            'S' => TypeNode::Start,
+            _ => unreachable!("Grid::parse solo deja pasar las tuberias"),
+        });
+        let start = map
+            .position(|node| *node == TypeNode::Start)
+            .ok_or_else(|| Error::parse(Day10::DAY, "no hay punto de inicio"))?;
+        Ok(Maze { map, start })
+    }
+
+    /// Every tile of the maze.
+    pub fn tiles(&self) -> &Grid<TypeNode> {
+        &self.map
+    }
+
+    /// Where the loop starts.
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// `true` for the tiles of the loop through the start.
+    pub fn pipes(&self) -> Result<Grid<bool>, Error> {
+        let mut visitados = Grid::new(self.map.width(), self.map.height(), false);
+        let mut current_point = self.start;
+        while !visitado(&visitados, current_point) {
+            let north = current_point.step(Direction::North);
+            let south = current_point.step(Direction::South);
+            let east = current_point.step(Direction::East);
+            let west = current_point.step(Direction::West);
+            let next_point = match *tipo(&self.map, &current_point) {
+                TypeNode::NorthSouth => {
+                    if !visitado(&visitados, north)
+                        && (*tipo(&self.map, &north) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &north) == TypeNode::SouthEast
+                            || *tipo(&self.map, &north) == TypeNode::SouthWest)
+                    {
+                        north
+                    } else if !visitado(&visitados, south)
+                        && (*tipo(&self.map, &south) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &south) == TypeNode::NorthEast
+                            || *tipo(&self.map, &south) == TypeNode::NorthWest)
+                    {
+                        south
+                    } else {
+                        current_point
+                    }
+                }
+                TypeNode::EastWest => {
+                    if !visitado(&visitados, east)
+                        && (*tipo(&self.map, &east) == TypeNode::EastWest
+                            || *tipo(&self.map, &east) == TypeNode::NorthWest
+                            || *tipo(&self.map, &east) == TypeNode::SouthWest)
+                    {
+                        east
+                    } else if !visitado(&visitados, west)
+                        && (*tipo(&self.map, &west) == TypeNode::EastWest
+                            || *tipo(&self.map, &west) == TypeNode::NorthEast
+                            || *tipo(&self.map, &west) == TypeNode::SouthEast)
+                    {
+                        west
+                    } else {
+                        current_point
+                    }
+                }
+                TypeNode::NorthEast => {
+                    if !visitado(&visitados, north)
+                        && (*tipo(&self.map, &north) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &north) == TypeNode::SouthEast
+                            || *tipo(&self.map, &north) == TypeNode::SouthWest)
+                    {
+                        north
+                    } else if !visitado(&visitados, east)
+                        && (*tipo(&self.map, &east) == TypeNode::EastWest
+                            || *tipo(&self.map, &east) == TypeNode::NorthWest
+                            || *tipo(&self.map, &east) == TypeNode::SouthWest)
+                    {
+                        east
+                    } else {
+                        current_point
+                    }
+                }
+                TypeNode::NorthWest => {
+                    if !visitado(&visitados, north)
+                        && (*tipo(&self.map, &north) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &north) == TypeNode::SouthEast
+                            || *tipo(&self.map, &north) == TypeNode::SouthWest)
+                    {
+                        north
+                    } else if !visitado(&visitados, west)
+                        && (*tipo(&self.map, &west) == TypeNode::EastWest
+                            || *tipo(&self.map, &west) == TypeNode::NorthEast
+                            || *tipo(&self.map, &west) == TypeNode::SouthEast)
+                    {
+                        west
+                    } else {
+                        current_point
+                    }
+                }
+                TypeNode::SouthWest => {
+                    if !visitado(&visitados, south)
+                        && (*tipo(&self.map, &south) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &south) == TypeNode::NorthEast
+                            || *tipo(&self.map, &south) == TypeNode::NorthWest)
+                    {
+                        south
+                    } else if !visitado(&visitados, west)
+                        && (*tipo(&self.map, &west) == TypeNode::EastWest
+                            || *tipo(&self.map, &west) == TypeNode::NorthEast
+                            || *tipo(&self.map, &west) == TypeNode::SouthEast)
+                    {
+                        west
+                    } else {
+                        current_point
+                    }
+                }
+
+                TypeNode::SouthEast => {
+                    if !visitado(&visitados, south)
+                        && (*tipo(&self.map, &south) == TypeNode::NorthSouth
+                            || *tipo(&self.map, &south) == TypeNode::NorthEast
+                            || *tipo(&self.map, &south) == TypeNode::NorthWest)
+                    {
+                        south
+                    } else if !visitado(&visitados, east)
+                        && (*tipo(&self.map, &east) == TypeNode::EastWest
+                            || *tipo(&self.map, &east) == TypeNode::NorthWest
+                            || *tipo(&self.map, &east) == TypeNode::SouthWest)
+                    {
+                        east
+                    } else {
+                        current_point
+                    }
+                }
+                TypeNode::Ground => {
+                    return Err(Error::solve(
+                        Day10::DAY,
+                        format!("Ground at {:?}", current_point),
+                    ));
+                }
+                TypeNode::Start => {
+                    if !visitado(&visitados, north)
+                        && match self.map.get(north) {
+                            Some(node) => {
+                                *node == TypeNode::NorthSouth
+                                    || *node == TypeNode::SouthEast
+                                    || *node == TypeNode::SouthWest
+                            }
+                            None => false,
+                        }
+                    {
+                        north
+                    } else if !visitado(&visitados, south)
+                        && match self.map.get(south) {
+                            Some(node) => {
+                                *node == TypeNode::NorthSouth
+                                    || *node == TypeNode::NorthEast
+                                    || *node == TypeNode::NorthWest
+                            }
+                            None => false,
+                        }
+                    {
+                        south
+                    } else if !visitado(&visitados, east)
+                        && match self.map.get(east) {
+                            Some(node) => {
+                                *node == TypeNode::EastWest
+                                    || *node == TypeNode::NorthWest
+                                    || *node == TypeNode::SouthWest
+                            }
+                            None => false,
+                        }
+                    {
+                        east
+                    } else if !visitado(&visitados, west)
+                        && match self.map.get(west) {
+                            Some(node) => {
+                                *node == TypeNode::EastWest
+                                    || *node == TypeNode::NorthEast
+                                    || *node == TypeNode::SouthEast
+                            }
+                            None => false,
+                        }
+                    {
+                        west
+                    } else {
+                        return Err(Error::solve(
+                            Day10::DAY,
+                            format!("No path at {:?}", current_point),
+                        ));
+                    }
+                }
+            };
+
+            visitados[current_point] = true;
+            current_point = next_point;
+        }
+        Ok(visitados)
+    }
+
+    /// Steps to the point of the loop farthest from the start.
+    pub fn farthest(&self) -> Result<u64, Error> {
+        let pipes = self.pipes()?;
+        let largo_ciclo = pipes.iter().filter(|(_, v)| **v).count() as u64;
+        Ok(largo_ciclo / 2 + largo_ciclo % 2) //siempre hay un ciclo
+    }
+
+    /// The loop and the tiles it encloses, counting the pipes crossed on the
+    /// way down each column.
+    pub fn enclosed(&self) -> Result<Enclosed, Error> {
+        let visitados = self.pipes()?;
+        let mut inside = Grid::new(self.map.width(), self.map.height(), false);
+        for x in 0..self.map.width() as i64 {
+            let mut letf = 0;
+            let mut right = 0;
+            for y in 0..self.map.height() as i64 {
+                match visitado(&visitados, Point { x, y }) {
+                    true => match *tipo(&self.map, &Point { x, y }) {
+                        TypeNode::EastWest => {
+                            letf += 1;
+                            right += 1;
+                        }
+                        TypeNode::NorthEast => {
+                            right += 1;
+                        }
+                        TypeNode::NorthWest => {
+                            letf += 1;
+                        }
+                        TypeNode::SouthWest => {
+                            letf += 1;
+                        }
+                        TypeNode::SouthEast => {
+                            right += 1;
+                        }
+
+                        TypeNode::Start => {
+                            match start_equivalence(&self.map, &visitados, Point { x, y })? {
+                                TypeNode::EastWest => {
+                                    letf += 1;
+                                    right += 1;
+                                }
+                                TypeNode::NorthEast => {
+                                    right += 1;
+                                }
+                                TypeNode::NorthWest => {
+                                    letf += 1;
+                                }
+                                TypeNode::SouthWest => {
+                                    letf += 1;
+                                }
+                                TypeNode::SouthEast => {
+                                    right += 1;
+                                }
+                                _ => {}
+                            }
+                        }
+                        _ => {}
+                    },
+
+                    false => {
+                        if letf.min(right) % 2 == 1 {
+                            inside[Point { x, y }] = true;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Enclosed {
+            pipes: visitados,
+            inside,
+        })
+    }
+}
+
+/// The [`Solution`] for day 10, the input is the maze.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Maze;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Maze, Error> {
+        Maze::new(input)
+    }
+
+    fn part1(maze: &Maze) -> Result<u64, Error> {
+        maze.farthest()
+    }
+
+    fn part2(maze: &Maze) -> Result<u64, Error> {
+        let inside = maze.enclosed()?.inside;
+        Ok(inside.iter().filter(|(_, v)| **v).count() as u64)
+    }
+}
+
+// The loop in box drawing characters, then the tiles it encloses.
+impl Visualize for Day10 {
+    fn frames(maze: &Maze) -> Result<Vec<Frame>, Error> {
+        let Enclosed { pipes, inside } = maze.enclosed()?;
+        let lines = maze.map.map(TypeNode::glyph);
+        let marked = |grid: &Grid<bool>| {
+            let marked = grid.iter().filter(|(_, v)| **v).map(|(p, _)| p);
+            marked.collect::<Vec<_>>()
+        };
+        let frame = |title| {
+            Frame::new(title, &lines)
+                .overlay(marked(&pipes), Rgb::LOOP)
+                .overlay([maze.start], Rgb::START)
+        };
+        Ok(vec![
+            frame("parte 1: el bucle"),
+            frame("parte 2: el interior").overlay(marked(&inside), Rgb::INSIDE),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    fn part1(input: &str) -> Result<u64, Error> {
+        Day10::part1(&Day10::parse(input)?)
+    }
+
+    fn part2(input: &str) -> Result<u64, Error> {
+        Day10::part2(&Day10::parse(input)?)
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn test_part1_2() {
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let result = part1(input).unwrap();
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = part1(".....\n.S-7.\n.|x|.").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+        assert!(part1(".....\n..-7.").is_err());
+        assert!(part1("S-7\n|.|\nL-J").is_ok());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        let result = part2(input).unwrap();
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn test_part2_2() {
+        let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let result = part2(input).unwrap();
+        assert_eq!(result, 8);
+    }
+    #[test]
+    fn test_part2_3() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        let result = part2(input).unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day10>(include_str!("input.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let maze = Day10::parse("S-7.\n|.|.\nL-J.").unwrap();
+        let frames = Day10::frames(&maze).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].color(Point::new(0, 0)), Rgb::START);
+        assert_eq!(frames[0].color(Point::new(1, 0)), Rgb::LOOP);
+        assert_eq!(frames[1].color(Point::new(1, 1)), Rgb::INSIDE);
+        assert_eq!(
+            frames[1].color(Point::new(3, 1)),
+            frames[1].color(Point::new(3, 0))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_10::Day10;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let maze = Day10::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day10::part1(&maze)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day10::part2(&maze)?,
+        now.elapsed()
+    );
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! Day 11: Cosmic Expansion. Distances between galaxies in an expanding
+//! universe.
+
+#![warn(missing_docs)]
+
+use aoc_core::{grid::Grid, Error, Point, Solution};
+
+/// The galaxies of the image and its rows and columns without any.
+#[derive(Debug)]
+pub struct Universe {
+    galaxys: Vec<Point>,
+    empty_rows: Vec<i64>,
+    empty_cols: Vec<i64>,
+}
+
+impl Universe {
+    /// Parses the image, `#` for a galaxy and `.` for empty space.
+    pub fn new(input: &str) -> Result<Universe, Error> {
+        let grid = Grid::parse(Day11::DAY, input, ".#")?;
+        let empty_rows = grid
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|&c| c == '.'))
+            .map(|(y, _)| y as i64)
+            .collect();
+        let empty_cols = (0..grid.width())
+            .filter(|&x| grid.column(x).all(|&c| c == '.'))
+            .map(|x| x as i64)
+            .collect();
+        let galaxys = grid
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(p, _)| p)
+            .collect();
+        Ok(Universe {
+            galaxys,
+            empty_rows,
+            empty_cols,
+        })
+    }
+
+    /// How many galaxies there are.
+    pub fn galaxies(&self) -> usize {
+        self.galaxys.len()
+    }
+
+    /// Sum of the distances between every pair of galaxies, with each empty
+    /// row and column counted `expansion` times.
+    pub fn distances(&self, expansion: usize) -> usize {
+        let extra = expansion.saturating_sub(1);
+        let mut sum = 0;
+        for (i, galaxy) in self.galaxys.iter().enumerate() {
+            for other in &self.galaxys[i + 1..] {
+                let distance = galaxy.manhattan_distance(other) as usize;
+                let (min_x, max_x) = (galaxy.x.min(other.x), galaxy.x.max(other.x));
+                let (min_y, max_y) = (galaxy.y.min(other.y), galaxy.y.max(other.y));
+                let rows_exp = self
+                    .empty_rows
+                    .iter()
+                    .filter(|&&y| y > min_y && y < max_y)
+                    .count();
+                let cols_exp = self
+                    .empty_cols
+                    .iter()
+                    .filter(|&&x| x > min_x && x < max_x)
+                    .count();
+                sum += distance + (rows_exp + cols_exp) * extra;
+            }
+        }
+        sum
+    }
+}
+
+/// The [`Solution`] for day 11, the input is the universe.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Universe;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Universe, Error> {
+        Universe::new(input)
+    }
+
+    fn part1(universe: &Universe) -> Result<usize, Error> {
+        Ok(universe.distances(2))
+    }
+
+    fn part2(universe: &Universe) -> Result<usize, Error> {
+        Ok(universe.distances(1000000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    fn universe() -> Universe {
+        Day11::parse(include_str!("test_input.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(universe().galaxies(), 9);
+        assert_eq!(Day11::part1(&universe()), Ok(374));
+    }
+
+    #[test]
+    fn test_distances() {
+        let universe = universe();
+        assert_eq!(universe.distances(1), 292);
+        assert_eq!(universe.distances(2), 374);
+        assert_eq!(universe.distances(10), 1030);
+        assert_eq!(universe.distances(100), 8410);
+    }
+
+    #[test]
+    fn test_grilla_invalida() {
+        let error = Day11::parse("#..\n.#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day11>(include_str!("test_input.txt"));
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::{Error, Solution};
+use day_11::Day11;
+
+fn main() -> Result<(), Error> {
+    let input = include_str!("input.txt");
+    let universe = Day11::parse(input)?;
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 1: {}\n time: {:?} ",
+        Day11::part1(&universe)?,
+        now.elapsed()
+    );
+    let now = std::time::Instant::now();
+    println!(
+        "solutions part 2: {}\n time: {:?}",
+        Day11::part2(&universe)?,
+        now.elapsed()
+    );
+    Ok(())
+}
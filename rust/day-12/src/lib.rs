@@ -0,0 +1,275 @@
+//! Day 12: Hot Springs. Condition records of damaged springs and how many
+//! arrangements of the unknown ones fit the damaged groups.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt};
+
+use aoc_core::{parse, Error, Solution};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, one_of, space1},
+    combinator::{map_res, verify},
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+/// The condition of one spring, `.`, `#` or `?` in the records.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum SpringType {
+    /// Working, `.`.
+    Operational,
+    /// Broken, `#`.
+    Damaged,
+    /// Either of the other two, `?`.
+    Unknown,
+}
+
+impl From<char> for SpringType {
+    fn from(c: char) -> Self {
+        match c {
+            '.' => SpringType::Operational,
+            '#' => SpringType::Damaged,
+            _ => SpringType::Unknown,
+        }
+    }
+}
+
+/// One row of springs with the sizes of its groups of damaged ones, in order.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Record {
+    springs: Vec<SpringType>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    /// A record with `springs` and the damaged `groups` they must form.
+    pub fn new(springs: Vec<SpringType>, groups: Vec<usize>) -> Self {
+        Record { springs, groups }
+    }
+
+    /// The springs in the row, from left to right.
+    pub fn springs(&self) -> &[SpringType] {
+        &self.springs
+    }
+
+    /// Sizes of the contiguous groups of damaged springs, from left to right.
+    pub fn groups(&self) -> &[usize] {
+        &self.groups
+    }
+
+    fn parse(input: &str) -> IResult<&str, Record> {
+        let (input, springs) = many1(one_of(".#?"))(input)?;
+        let (input, _) = space1(input)?;
+        let group = verify(map_res(digit1, str::parse), |&n: &usize| n > 0);
+        let (input, grops) = separated_list1(tag(","), group)(input)?;
+        Ok((
+            input,
+            Record::new(
+                springs.into_iter().map(|c| c.into()).collect(),
+                grops,
+            ),
+        ))
+    }
+
+    /// Parses one record per line, like `???.### 1,1,3`.
+    pub fn parse_all(input: &str) -> Result<Vec<Record>, Error> {
+        parse::lines(Day12::DAY, input, Record::parse)
+    }
+
+    /// The record unfolded for part 2: the springs five times joined by an
+    /// unknown one, and the groups five times.
+    pub fn expand(&self) -> Record {
+        let sptrings = self
+            .springs
+            .iter()
+            .cloned()
+            .chain([SpringType::Unknown].iter().cloned())
+            .cycle()
+            .take(self.springs.len() * 5 + 4)
+            .collect();
+        let groups = self
+            .groups
+            .iter()
+            .cloned()
+            .cycle()
+            .take(self.groups.len() * 5)
+            .collect();
+        Record::new(sptrings, groups)
+    }
+
+    /// How many ways the unknown springs can be filled in so the damaged ones
+    /// form exactly the record's groups.
+    pub fn arrangements(&self) -> usize {
+        posible_solutions(&mut HashMap::new(), self)
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for spring in &self.springs {
+            let c = match spring {
+                SpringType::Operational => '.',
+                SpringType::Damaged => '#',
+                SpringType::Unknown => '?',
+            };
+            write!(f, "{}", c)?;
+        }
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>();
+        write!(f, " {}", groups.join(","))
+    }
+}
+
+fn posible_solutions(memo: &mut HashMap<Record, usize>, record: &Record) -> usize {
+    if let Some(&value) = memo.get(record) {
+        return value;
+    }
+
+    //si no quedan numeros, o tengo una solución o no es valido. Si queda algun dañado no tiene solucion
+    if record.groups.is_empty() {
+        let value = match record.springs.contains(&SpringType::Damaged) {
+            true => 0,
+            false => 1,
+        };
+        memo.insert(record.clone(), value);
+        return value;
+    };
+
+    //chequeo si hay suficiente espacio para colocar los dañados restantes
+    if record.springs.len() < record.groups.iter().sum::<usize>() + record.groups.len() - 1 {
+        memo.insert(record.clone(), 0);
+        return 0;
+    }
+
+    //si el primero es operativo, no puedo colocar un dañado, armo el nuevo record ignorando el primero
+    if record.springs[0] == SpringType::Operational {
+        let solutions = posible_solutions(
+            memo,
+            &Record::new(record.springs[1..].to_vec(), record.groups.clone()),
+        );
+        memo.insert(record.clone(), solutions);
+        return solutions;
+    }
+
+    let mut solutions = 0;
+    let current_group = record.groups[0];
+    let all_non_operational = record.springs[0..current_group]
+        .iter()
+        .all(|c| *c != SpringType::Operational);
+    //el final va a ser el que este despues o el final del spring
+    let end = (current_group + 1).min(record.springs.len());
+
+    //si se cumplen las condiciones puedo colocar el grupo de dañados en la posicion actual y moverme a la siguiente
+    //La siguiente posicion es la que esta uno mas despues del grupo ej: en ##..## la posicion luego del primer
+    //par de ## es ##.[.]##. Esta posicion tiene que ser un . o fin de string
+    if all_non_operational
+        && ((record.springs.len() > current_group
+            && record.springs[current_group] != SpringType::Damaged)
+            || record.springs.len() <= current_group)
+    {
+        solutions = posible_solutions(
+            memo,
+            &Record::new(record.springs[end..].to_vec(), record.groups[1..].to_vec()),
+        );
+    }
+
+    //si el primer spring es desconocido, puedo no usar el grupo en esta posicion
+    if record.springs[0] == SpringType::Unknown {
+        solutions += posible_solutions(
+            memo,
+            &Record::new(record.springs[1..].to_vec(), record.groups.clone()),
+        );
+    }
+    memo.insert(record.clone(), solutions);
+    solutions
+}
+
+/// Parses the records and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let records = Record::parse_all(input)?;
+    Ok(records.iter().map(|r| format!("{}\n", r)).collect())
+}
+
+/// The [`Solution`] for day 12, parts 1 and 2 sum the arrangements of the
+/// records as given and unfolded.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Record>, Error> {
+        Record::parse_all(input)
+    }
+
+    fn part1(records: &Vec<Record>) -> Result<usize, Error> {
+        let mut memo = HashMap::new();
+        Ok(records.iter().map(|r| posible_solutions(&mut memo, r)).sum())
+    }
+
+    fn part2(records: &Vec<Record>) -> Result<usize, Error> {
+        let mut memo = HashMap::new();
+        Ok(records
+            .iter()
+            .map(|r| posible_solutions(&mut memo, &r.expand()))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        let records = Record::parse_all(input).unwrap();
+        let mut memo = HashMap::new();
+        let solutions = records
+            .iter()
+            .map(|r| posible_solutions(&mut memo, r))
+            .sum::<usize>();
+        assert_eq!(solutions, 21);
+    }
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        let records = Record::parse_all(input).unwrap();
+        let mut memo = HashMap::new();
+        let solutions = records
+            .iter()
+            .map(|r| posible_solutions(&mut memo, &r.expand()))
+            .sum::<usize>();
+        assert_eq!(solutions, 525152);
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let record = &Record::parse_all("?###???????? 3,2,1").unwrap()[0];
+        assert_eq!(record.groups(), [3, 2, 1]);
+        assert_eq!(record.springs()[1], SpringType::Damaged);
+        assert_eq!(record.arrangements(), 10);
+        assert_eq!(record.expand().arrangements(), 506250);
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "???.### 1,01,3\n.#?  2";
+        assert_eq!(rewrite(input).unwrap(), "???.### 1,1,3\n.#? 2\n");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day12>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Day 13: Point of Incidence. Patterns of ash and rocks and the line each
+//! one is mirrored across, with or without fixing one smudge.
+
+#![warn(missing_docs)]
+
+use std::iter::zip;
+
+use aoc_core::{grid::Grid, parse, Error, Solution};
+
+/// One pattern of ash `.` and rocks `#`.
+#[derive(Debug)]
+pub struct Mirrors {
+    rows: Grid<char>,
+    /// The pattern transposed, so columns are compared like rows.
+    columns: Grid<char>,
+}
+
+impl Mirrors {
+    /// Parses a single pattern, without blank lines.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let rows = Grid::parse(Day13::DAY, input, ".#")?;
+        let columns = rows.transpose();
+        Ok(Mirrors { rows, columns })
+    }
+
+    /// The pattern as it was read.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.rows
+    }
+
+    fn equal_rows(&self, primera: usize, segunda: usize) -> bool {
+        self.rows.row(primera) == self.rows.row(segunda)
+    }
+
+    fn equal_columns(&self, primera: usize, segunda: usize) -> bool {
+        self.columns.row(primera) == self.columns.row(segunda)
+    }
+
+    fn diferences_in_rows(&self, primera: usize, segunda: usize) -> usize {
+        zip(self.rows.row(primera), self.rows.row(segunda))
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    fn diferences_in_columns(&self, primera: usize, segunda: usize) -> usize {
+        zip(self.columns.row(primera), self.columns.row(segunda))
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// Summary of the line the pattern mirrors across: the rows above it times
+    /// 100 for a horizontal line, the columns to its left for a vertical one.
+    pub fn solve_perfect_mirror(&self) -> Option<usize> {
+        for i in 0..self.rows.height() - 1 {
+            if self.equal_rows(i, i + 1) {
+                let min_dist = i.min(self.rows.height() - i - 2);
+                let mut is_mirror = true;
+                for j in 1..=min_dist {
+                    is_mirror = is_mirror && self.equal_rows(i - j, i + j + 1);
+                }
+                if is_mirror {
+                    return Some((i + 1) * 100);
+                }
+            }
+        }
+        for i in 0..self.columns.height() - 1 {
+            if self.equal_columns(i, i + 1) {
+                let min_dist = i.min(self.columns.height() - i - 2);
+                let mut is_mirror = true;
+                for j in 1..=min_dist {
+                    is_mirror = is_mirror && self.equal_columns(i - j, i + j + 1);
+                }
+                if is_mirror {
+                    return Some(i + 1);
+                }
+            }
+        }
+        None
+    }
+
+    /// Like [`Mirrors::solve_perfect_mirror`], for the line that needs exactly
+    /// one cell changed to be a mirror.
+    pub fn solve_one_smudge(&self) -> Option<usize> {
+        for i in 0..self.rows.height() - 1 {
+            let mut dif = self.diferences_in_rows(i, i + 1);
+            if dif <= 1 {
+                let min_dist = i.min(self.rows.height() - i - 2);
+                let mut is_mirror = true;
+                for j in 1..=min_dist {
+                    dif += self.diferences_in_rows(i - j, i + j + 1);
+                    is_mirror = is_mirror && dif <= 1;
+                }
+                if is_mirror && dif == 1 {
+                    return Some((i + 1) * 100);
+                }
+            }
+        }
+
+        for i in 0..self.columns.height() - 1 {
+            let mut dif = self.diferences_in_columns(i, i + 1);
+            if dif <= 1 {
+                let min_dist = i.min(self.columns.height() - i - 2);
+                let mut is_mirror = true;
+                for j in 1..=min_dist {
+                    dif += self.diferences_in_columns(i - j, i + j + 1);
+                    is_mirror = is_mirror && dif <= 1;
+                }
+                if is_mirror && dif == 1 {
+                    return Some(i + 1);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Parses the patterns, separated by blank lines. Error lines count from the
+/// start of the input.
+pub fn parse_input(input: &str) -> Result<Vec<Mirrors>, Error> {
+    let blocks = parse::blocks(input);
+    if blocks.is_empty() {
+        return Err(Error::parse(Day13::DAY, "la grilla esta vacia"));
+    }
+    blocks
+        .into_iter()
+        .map(|(first, block)| {
+            // los errores de cada patron cuentan lineas desde su comienzo
+            Mirrors::new(block).map_err(|e| Error {
+                line: e.line.map(|l| l + first - 1),
+                ..e
+            })
+        })
+        .collect()
+}
+
+/// Adds up `reflection` for every pattern, failing on the first one without a
+/// line.
+pub fn sum_reflections(
+    mirrors: &[Mirrors],
+    reflection: impl Fn(&Mirrors) -> Option<usize>,
+) -> Result<usize, Error> {
+    mirrors.iter().enumerate().try_fold(0, |acc, (i, m)| {
+        let value = reflection(m).ok_or_else(|| {
+            Error::solve(Day13::DAY, format!("el patron {} no tiene reflejo", i + 1))
+        })?;
+        Ok(acc + value)
+    })
+}
+
+/// The [`Solution`] for day 13.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<Mirrors>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Mirrors>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(mirrors: &Vec<Mirrors>) -> Result<usize, Error> {
+        sum_reflections(mirrors, Mirrors::solve_perfect_mirror)
+    }
+
+    fn part2(mirrors: &Vec<Mirrors>) -> Result<usize, Error> {
+        sum_reflections(mirrors, Mirrors::solve_one_smudge)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+    #[test]
+    fn test_parte1() {
+        let input = include_str!("input_test.txt");
+        let mirrors = parse_input(input).unwrap();
+        let result = mirrors
+            .iter()
+            .map(|m| m.solve_perfect_mirror().unwrap())
+            .sum::<usize>();
+        assert_eq!(result, 405);
+    }
+    #[test]
+    fn test_diferences_in_rows() {
+        let input = include_str!("input_test.txt");
+        let mirrors = parse_input(input).unwrap();
+        let result = mirrors[0].diferences_in_rows(0, 1);
+        assert_eq!(result, 5);
+        let result = mirrors[0].diferences_in_rows(2, 3);
+        assert_eq!(result, 0)
+    }
+    #[test]
+    fn test_diferences_in_columns() {
+        let input = include_str!("input_test.txt");
+        let mirrors = parse_input(input).unwrap();
+        let result = mirrors[0].diferences_in_columns(0, 1);
+        assert_eq!(result, 2);
+        let result = mirrors[0].diferences_in_columns(4, 5);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_parte2() {
+        let input = include_str!("input_test.txt");
+        let result = Day13::part2(&Day13::parse(input).unwrap()).unwrap();
+        assert_eq!(result, 400);
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day13::parse("#.\n.#\n\n#.\n.x").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(5), Some(2)));
+        let error = Day13::part1(&Day13::parse("#.\n.#").unwrap()).unwrap_err();
+        assert_eq!(error.message, "el patron 1 no tiene reflejo");
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day13>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Day 14: Parabolic Reflector Dish. A platform of rounded rocks `O` that
+//! roll when it is tilted and cube rocks `#` that stop them.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, hash::Hasher};
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Error, Point, Solution, Visualize,
+};
+
+#[derive(Debug, Eq)]
+struct State {
+    plataform: Plataform,
+    id: usize,
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.plataform == other.plataform
+    }
+}
+
+impl std::hash::Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.plataform.hash(state);
+    }
+}
+impl State {
+    fn new(plataform: Plataform, id: usize) -> Self {
+        State { plataform, id }
+    }
+}
+
+/// The platform with every rock where it is.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Plataform {
+    grid: Grid<char>,
+}
+
+fn at(x: usize, y: usize) -> Point {
+    Point::new(x as i64, y as i64)
+}
+
+impl Plataform {
+    /// Parses the platform, only `O`, `#` and `.` are allowed.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse(Day14::DAY, input, "O#.")?;
+        Ok(Plataform { grid })
+    }
+
+    /// The platform as a grid of `O`, `#` and `.`.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Load on the north support beams: each rounded rock adds its distance
+    /// to the south edge plus one.
+    pub fn calculate_score(&self) -> usize {
+        self.grid
+            .rows()
+            .enumerate()
+            .map(|(i, row)| row.iter().filter(|c| **c == 'O').count() * (self.grid.height() - i))
+            .sum()
+    }
+
+    /// Tilts the platform north, every rounded rock rolls as far as it can.
+    pub fn move_north(&mut self) {
+        for x in 0..self.grid.width() {
+            for y in 1..self.grid.height() {
+                if self.grid[at(x, y)] == 'O' && self.grid[at(x, y - 1)] == '.' {
+                    let mut change = (y - 1) as isize;
+                    while change >= 0 && self.grid[at(x, change as usize)] == '.' {
+                        change -= 1;
+                    }
+                    self.grid[at(x, (change + 1) as usize)] = 'O';
+                    self.grid[at(x, y)] = '.';
+                }
+            }
+        }
+    }
+
+    /// Tilts the platform south, every rounded rock rolls as far as it can.
+    pub fn move_south(&mut self) {
+        for x in 0..self.grid.width() {
+            for y in (0..self.grid.height() - 1).rev() {
+                if self.grid[at(x, y)] == 'O' && self.grid[at(x, y + 1)] == '.' {
+                    let mut change = y + 1;
+                    while change < self.grid.height() && self.grid[at(x, change)] == '.' {
+                        change += 1;
+                    }
+                    self.grid[at(x, change - 1)] = 'O';
+                    self.grid[at(x, y)] = '.';
+                }
+            }
+        }
+    }
+    /// Tilts the platform west, every rounded rock rolls as far as it can.
+    pub fn move_west(&mut self) {
+        for y in 0..self.grid.height() {
+            for x in 1..self.grid.width() {
+                if self.grid[at(x, y)] == 'O' && self.grid[at(x - 1, y)] == '.' {
+                    let mut change = (x - 1) as isize;
+                    while change >= 0 && self.grid[at(change as usize, y)] == '.' {
+                        change -= 1;
+                    }
+                    self.grid[at((change + 1) as usize, y)] = 'O';
+                    self.grid[at(x, y)] = '.';
+                }
+            }
+        }
+    }
+
+    /// Tilts the platform east, every rounded rock rolls as far as it can.
+    pub fn move_east(&mut self) {
+        for y in 0..self.grid.height() {
+            for x in (0..self.grid.width() - 1).rev() {
+                if self.grid[at(x, y)] == 'O' && self.grid[at(x + 1, y)] == '.' {
+                    let mut change = x + 1;
+                    while change < self.grid.width() && self.grid[at(change, y)] == '.' {
+                        change += 1;
+                    }
+                    self.grid[at(change - 1, y)] = 'O';
+                    self.grid[at(x, y)] = '.';
+                }
+            }
+        }
+    }
+    /// One spin cycle, tilting north, west, south and east.
+    pub fn cycle(&mut self) {
+        self.move_north();
+        self.move_west();
+        self.move_south();
+        self.move_east();
+    }
+}
+
+/// The [`Solution`] for day 14.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Plataform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Plataform, Error> {
+        Plataform::new(input)
+    }
+
+    fn part1(plataform: &Plataform) -> Result<usize, Error> {
+        let mut plataform = plataform.clone();
+        plataform.move_north();
+        Ok(plataform.calculate_score())
+    }
+
+    fn part2(plataform: &Plataform) -> Result<usize, Error> {
+        let mut calculados = HashSet::new();
+        let mut plataform = plataform.clone();
+        for i in 0..1000000000 {
+            calculados.insert(State::new(plataform.clone(), i));
+            plataform.cycle();
+            if let Some(state) = calculados.get(&State::new(plataform.clone(), i)) {
+                let cycle_len = i + 1 - state.id;
+                let remaining = 1000000000 - i - 1;
+                let remaining = remaining % cycle_len;
+                for _ in 0..remaining {
+                    //tambien se pueede obtener el ya calculado con el id i + remaining-cycle_len+1
+                    plataform.cycle();
+                }
+
+                return Ok(plataform.calculate_score());
+            }
+        }
+        Err(Error::solve(Self::DAY, "la plataforma nunca repite un estado"))
+    }
+}
+
+/// The platform with the rocks that rolled since `before` highlighted.
+fn frame(title: String, before: &Plataform, after: &Plataform) -> Frame {
+    let rolled = after
+        .grid
+        .iter()
+        .filter(|(p, c)| **c == 'O' && before.grid[*p] != 'O')
+        .map(|(p, _)| p);
+    Frame::new(title, &after.grid).overlay(rolled.collect::<Vec<_>>(), Rgb::PATH)
+}
+
+// Part 1's tilt, then one frame per spin cycle until the platform repeats.
+impl Visualize for Day14 {
+    fn frames(plataform: &Plataform) -> Result<Vec<Frame>, Error> {
+        let mut north = plataform.clone();
+        north.move_north();
+        let mut frames = vec![
+            Frame::new("inicio", &plataform.grid),
+            frame("parte 1: inclinada al norte".to_owned(), plataform, &north),
+        ];
+        let mut vistos = HashSet::new();
+        let mut current = plataform.clone();
+        for i in 1.. {
+            vistos.insert(current.clone());
+            let mut next = current.clone();
+            next.cycle();
+            frames.push(frame(format!("parte 2: ciclo {}", i), &current, &next));
+            if vistos.contains(&next) {
+                break;
+            }
+            current = next;
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day14::part1(&Day14::parse(input).unwrap()).unwrap(), 136);
+    }
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day14::part2(&Day14::parse(input).unwrap()).unwrap(), 64);
+    }
+    #[test]
+    fn test_caracter_invalido() {
+        let error = Day14::parse("O.#\n.x.").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day14>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let plataform = Day14::parse("#.\nO.\n.O").unwrap();
+        let frames = Day14::frames(&plataform).unwrap();
+        assert_eq!(frames[1].title, "parte 1: inclinada al norte");
+        assert_eq!(frames[1].color(Point::new(1, 0)), Rgb::PATH);
+        assert_ne!(frames[1].color(Point::new(0, 1)), Rgb::PATH);
+        // The first cycle leaves both rocks in the bottom row for good.
+        assert_eq!(frames.len(), 4);
+    }
+}
@@ -0,0 +1,278 @@
+//! Day 15: Lens Library. The HASH algorithm and the HASHMAP procedure that
+//! puts lenses in 256 boxes.
+
+#![warn(missing_docs)]
+
+use std::fmt;
+
+use aoc_core::{parse, Error, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1},
+    combinator::{map, map_res},
+    sequence::preceded,
+    IResult,
+};
+
+/// The HASH of `text`: for each character add its code, multiply by 17 and
+/// keep the remainder of dividing by 256.
+pub fn hash(text: &str) -> usize {
+    text.chars()
+        .fold(0, |acc, next_char| (acc + next_char as usize) * 17 % 256)
+}
+
+/// What a step does with the lens of its label.
+#[derive(Debug)]
+pub enum Operation {
+    /// `-`, takes the lens out of its box.
+    Remove,
+    /// `=` and a focal length, puts the lens in its box or replaces it.
+    Add(usize),
+}
+
+/// One step of the initialization sequence, like `rn=1` or `cm-`.
+#[derive(Debug)]
+pub struct Step<'a> {
+    /// The lens the step is about, its HASH is the box.
+    pub label: &'a str,
+    /// What to do with it.
+    pub operation: Operation,
+}
+impl<'a> Step<'a> {
+    /// A step doing `operation` with the lens `label`.
+    pub fn new(label: &'a str, operation: Operation) -> Self {
+        Step { label, operation }
+    }
+}
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Operation::Remove => write!(f, "{}-", self.label),
+            Operation::Add(focal_length) => write!(f, "{}={}", self.label, focal_length),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Lens<'a> {
+    label: &'a str,
+    focal_length: usize,
+}
+impl<'a> Lens<'a> {
+    fn new(label: &'a str, focal_length: usize) -> Self {
+        Lens {
+            label,
+            focal_length,
+        }
+    }
+    fn hash(&self) -> usize {
+        hash(self.label)
+    }
+}
+impl<'a> PartialEq for Lens<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+    }
+}
+
+/// The 256 boxes, each with its lenses in order.
+#[derive(Debug)]
+pub struct Boxes<'a> {
+    boxes: Vec<Vec<Lens<'a>>>,
+}
+impl<'a> Boxes<'a> {
+    /// Every box empty.
+    pub fn new() -> Self {
+        Boxes {
+            boxes: vec![vec![]; 256],
+        }
+    }
+
+    fn add_lens(&mut self, lens_to_add: Lens<'a>) {
+        for lens in self.boxes[lens_to_add.hash()].iter_mut() {
+            if lens == &lens_to_add {
+                lens.focal_length = lens_to_add.focal_length;
+                return;
+            }
+        }
+        self.boxes[lens_to_add.hash()].push(lens_to_add);
+    }
+
+    fn remove_lens(&mut self, lens_to_remove: Lens<'a>) {
+        self.boxes[lens_to_remove.hash()].retain(|lens| lens != &lens_to_remove);
+    }
+
+    /// Applies one step of the HASHMAP procedure.
+    pub fn do_step(&mut self, step: &Step<'a>) {
+        match step.operation {
+            Operation::Add(focal_length) => {
+                self.add_lens(Lens::new(step.label, focal_length));
+            }
+            Operation::Remove => {
+                self.remove_lens(Lens::new(step.label, 0));
+            }
+        }
+    }
+    /// Applies the steps in order.
+    pub fn do_steps(&mut self, steps: &[Step<'a>]) {
+        for step in steps {
+            self.do_step(step);
+        }
+    }
+    /// The labels and focal lengths of the lenses in box `number`, front to
+    /// back.
+    pub fn lenses(&self, number: usize) -> impl Iterator<Item = (&'a str, usize)> + '_ {
+        self.boxes[number]
+            .iter()
+            .map(|lens| (lens.label, lens.focal_length))
+    }
+
+    /// Sum over every lens of its box number, its slot and its focal length
+    /// multiplied, all counting from one.
+    pub fn focusion_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(box_number, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(|(lens_number, lens)| {
+                        (box_number + 1) * (lens_number + 1) * lens.focal_length
+                    })
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+impl Default for Boxes<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn step(input: &str) -> IResult<&str, Step<'_>> {
+    let (input, label) = alpha1(input)?;
+    let (input, operation) = alt((
+        map(tag("-"), |_| Operation::Remove),
+        map(
+            preceded(tag("="), map_res(digit1, str::parse)),
+            Operation::Add,
+        ),
+    ))(input)?;
+    Ok((input, Step::new(label, operation)))
+}
+
+/// The comma separated initialization sequence, both as raw text to hash and
+/// as parsed steps.
+#[derive(Debug)]
+pub struct Sequence<'a> {
+    raw: Vec<&'a str>,
+    steps: Vec<Step<'a>>,
+}
+
+impl<'a> Sequence<'a> {
+    /// The steps as written, for hashing.
+    pub fn raw(&self) -> &[&'a str] {
+        &self.raw
+    }
+
+    /// The parsed steps.
+    pub fn steps(&self) -> &[Step<'a>] {
+        &self.steps
+    }
+}
+
+impl fmt::Display for Sequence<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps = self.steps.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", steps.join(","))
+    }
+}
+
+/// Parses the sequence and writes it back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    Ok(Day15::parse(input)?.to_string())
+}
+
+/// The [`Solution`] for day 15.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Sequence<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Sequence<'_>, Error> {
+        let raw: Vec<&str> = parse::trimmed(input).split(',').collect();
+        let steps = raw
+            .iter()
+            .map(|s| parse::within(Self::DAY, input, s, step))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Sequence { raw, steps })
+    }
+
+    fn part1(sequence: &Sequence<'_>) -> Result<usize, Error> {
+        Ok(sequence.raw.iter().map(|string| hash(string)).sum())
+    }
+
+    fn part2(sequence: &Sequence<'_>) -> Result<usize, Error> {
+        let mut boxes = Boxes::new();
+        boxes.do_steps(&sequence.steps);
+        Ok(boxes.focusion_power())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+    #[test]
+    fn test_parte1() {
+        let input = include_str!("input_test.txt");
+        let result = Day15::part1(&Day15::parse(input).unwrap()).unwrap();
+        assert_eq!(result, 1320);
+    }
+    #[test]
+    fn test_boxes() {
+        assert_eq!(hash("HASH"), 52);
+        let sequence = Day15::parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4").unwrap();
+        let mut boxes = Boxes::new();
+        boxes.do_steps(sequence.steps());
+        assert_eq!(boxes.lenses(0).collect::<Vec<_>>(), [("rn", 1), ("cm", 2)]);
+        assert_eq!(boxes.lenses(1).count(), 0);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = include_str!("input.txt");
+        let result = Day15::part1(&Day15::parse(input).unwrap()).unwrap();
+        assert_eq!(result, 518107);
+    }
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        let result = Day15::part2(&Day15::parse(input).unwrap()).unwrap();
+        assert_eq!(result, 145);
+    }
+    #[test]
+    fn test_paso_invalido() {
+        let error = Day15::parse("rn=1,cm-,qp=x").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(13)));
+    }
+    #[test]
+    fn test_rewrite() {
+        let input = "rn=01,cm-,qp=3";
+        assert_eq!(rewrite(input).unwrap(), "rn=1,cm-,qp=3");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day15>(include_str!("input_test.txt"));
+    }
+}
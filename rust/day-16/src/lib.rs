@@ -0,0 +1,494 @@
+//! Day 16: The Floor Will Be Lava. A beam of light bouncing off mirrors and
+//! splitters, and how many tiles it energizes.
+
+#![warn(missing_docs)]
+
+use std::collections::VecDeque;
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Direction, Error, Point, Solution, Visualize,
+};
+use rayon::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+}
+
+impl Beam {
+    fn new(position: Point, direction: Direction) -> Self {
+        Beam {
+            position,
+            direction,
+        }
+    }
+
+    fn is_valid_position(&self, grid: &Contraption) -> bool {
+        grid.grid.contains(self.position)
+    }
+
+    fn move_beam(&mut self, grid: &Contraption) -> (Option<Beam>, Option<Beam>) {
+        let mut left = None;
+        let mut right = None;
+        let current_char = grid.grid[self.position];
+        match self.direction {
+            Direction::North => match current_char {
+                '.' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '/' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '\\' => {
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '|' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '-' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        right = Some(next_beam)
+                    }
+                }
+                _ => unreachable!("Contraption::new solo acepta .|-/\\"),
+            },
+            Direction::South => match current_char {
+                '.' => {
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '/' => {
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '\\' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '|' => {
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '-' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        right = Some(next_beam)
+                    }
+                }
+                _ => unreachable!("Contraption::new solo acepta .|-/\\"),
+            },
+            Direction::East => match current_char {
+                '.' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '/' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '\\' => {
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '|' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        right = Some(next_beam)
+                    }
+                }
+                '-' => {
+                    let next_x = self.position.x + 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::East);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                _ => {}
+            },
+            Direction::West => match current_char {
+                '.' => {
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '/' => {
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '\\' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                '|' => {
+                    let next_y = self.position.y - 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::North);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                    let next_y = self.position.y + 1;
+                    let next_beam =
+                        Beam::new(Point::new(self.position.x, next_y), Direction::South);
+                    if next_beam.is_valid_position(grid) {
+                        right = Some(next_beam)
+                    }
+                }
+                '-' => {
+                    let next_x = self.position.x - 1;
+                    let next_beam =
+                        Beam::new(Point::new(next_x, self.position.y), Direction::West);
+                    if next_beam.is_valid_position(grid) {
+                        left = Some(next_beam)
+                    }
+                }
+                _ => unreachable!("Contraption::new solo acepta .|-/\\"),
+            },
+        }
+        (left, right)
+    }
+}
+
+/// The contraption: empty space `.`, mirrors `/` and `\` and splitters `|` and `-`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Contraption {
+    grid: Grid<char>,
+}
+
+impl Contraption {
+    /// Parses the contraption.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse(Day16::DAY, input, ".|-/\\")?;
+        Ok(Contraption { grid })
+    }
+
+    /// The tiles as they were read.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// How many tiles a beam entering at `position` heading `direction`
+    /// energizes, the entry tile included.
+    pub fn energized(&self, position: Point, direction: Direction) -> usize {
+        self.energized_tiles(Beam::new(position, direction))
+    }
+
+    fn energized_tiles(&self, start_bean: Beam) -> usize {
+        self.beams(start_bean)
+            .iter()
+            .filter(|(_, directions)| directions.contains(&true))
+            .count()
+    }
+
+    // the directions a beam already went through each tile
+    fn beams(&self, start_bean: Beam) -> Grid<[bool; 4]> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let mut calculated_beams = Grid::new(width, height, [false; 4]);
+        let mut cola = VecDeque::new();
+        cola.push_back(start_bean);
+        while let Some(mut current_beam) = cola.pop_front() {
+            let directions = &mut calculated_beams[current_beam.position];
+            let calculated = &mut directions[current_beam.direction as usize];
+            if !*calculated {
+                *calculated = true;
+                let (left, right) = current_beam.move_beam(self);
+                if let Some(left) = left {
+                    cola.push_back(left);
+                }
+                if let Some(right) = right {
+                    cola.push_back(right);
+                }
+            }
+        }
+        calculated_beams
+    }
+
+    /// Energized tiles from `start_bean`, empty tiles show the beam like the
+    /// puzzle does: an arrow or how many beams cross it.
+    fn frame(&self, title: String, start_bean: Beam) -> Frame {
+        let beams = self.beams(start_bean);
+        let beams = beams.iter().filter_map(|(point, directions)| {
+            let count = directions.iter().filter(|d| **d).count();
+            let glyph = match (self.grid[point], count) {
+                (_, 0) => return None,
+                ('.', 1) => {
+                    let arrows = ['^', 'v', '>', '<'];
+                    arrows[directions.iter().position(|d| *d).unwrap_or_default()]
+                }
+                ('.', count) => char::from_digit(count as u32, 10).unwrap_or('*'),
+                (tile, _) => tile,
+            };
+            Some((point, glyph))
+        });
+        Frame::new(title, &self.grid).glyphs(beams.collect::<Vec<_>>(), Rgb::ENERGIZED)
+    }
+}
+
+/// Tiles energized by a beam entering the top left tile heading east.
+pub fn process_part1(grid: &Contraption) -> usize {
+    let start_beam = Beam::new(Point::new(0, 0), Direction::East);
+    grid.energized_tiles(start_beam)
+}
+
+/// The most tiles energized by a beam entering from any edge tile, heading
+/// away from that edge.
+pub fn process_part2(grid: &Contraption) -> usize {
+    let mut max = (0..grid.grid.height()).fold(0, |mut max, y| {
+        let start_beam = Beam::new(Point::new(0, y as i64), Direction::East);
+        max = max.max(grid.energized_tiles(start_beam));
+        max
+    });
+    max = (0..grid.grid.width()).fold(max, |mut max, x| {
+        let start_beam = Beam::new(Point::new(x as i64, 0), Direction::South);
+        max = max.max(grid.energized_tiles(start_beam));
+        max
+    });
+    let last_row = grid.grid.height() - 1;
+    max = (0..grid.grid.width()).fold(max, |mut max, x| {
+        let start_beam = Beam::new(Point::new(x as i64, last_row as i64), Direction::North);
+        max = max.max(grid.energized_tiles(start_beam));
+        max
+    });
+    let last_column = grid.grid.width() - 1;
+    max = (0..grid.grid.height()).fold(max, |mut max, y| {
+        let start_beam = Beam::new(Point::new(last_column as i64, y as i64), Direction::West);
+        max = max.max(grid.energized_tiles(start_beam));
+        max
+    });
+    max
+}
+
+/// [`process_part2`] trying the edge tiles in parallel.
+pub fn process_part2_par(grid: &Contraption) -> usize {
+    let max_row_east = (0..grid.grid.height())
+        .into_par_iter()
+        .map(|y| {
+            let start_beam = Beam::new(Point::new(0, y as i64), Direction::East);
+            grid.energized_tiles(start_beam)
+        })
+        .reduce(|| 0, |max, current| max.max(current));
+
+    let max_column_south = (0..grid.grid.width())
+        .into_par_iter()
+        .map(|x| {
+            let start_beam = Beam::new(Point::new(x as i64, 0), Direction::South);
+            grid.energized_tiles(start_beam)
+        })
+        .reduce(|| 0, |max, current| max.max(current));
+
+    let last_row = grid.grid.height() - 1;
+    let max_row_north = (0..grid.grid.width())
+        .into_par_iter()
+        .map(|x| {
+            let start_beam = Beam::new(Point::new(x as i64, last_row as i64), Direction::North);
+            grid.energized_tiles(start_beam)
+        })
+        .reduce(|| 0, |max, current| max.max(current));
+
+    let last_column = grid.grid.width() - 1;
+    let max = (0..grid.grid.height())
+        .into_par_iter()
+        .map(|y| {
+            let start_beam = Beam::new(Point::new(last_column as i64, y as i64), Direction::West);
+            grid.energized_tiles(start_beam)
+        })
+        .reduce(|| 0, |max, current| max.max(current));
+
+    max.max(max_row_east)
+        .max(max_column_south)
+        .max(max_row_north)
+}
+
+/// The [`Solution`] for day 16, part 2 runs in parallel.
+pub struct Day16;
+
+// Part 1's beam and the best one from any edge for part 2.
+impl Visualize for Day16 {
+    fn frames(grid: &Contraption) -> Result<Vec<Frame>, Error> {
+        let (width, height) = (grid.grid.width() as i64, grid.grid.height() as i64);
+        let starts = (0..height)
+            .flat_map(|y| {
+                [
+                    Beam::new(Point::new(0, y), Direction::East),
+                    Beam::new(Point::new(width - 1, y), Direction::West),
+                ]
+            })
+            .chain((0..width).flat_map(|x| {
+                [
+                    Beam::new(Point::new(x, 0), Direction::South),
+                    Beam::new(Point::new(x, height - 1), Direction::North),
+                ]
+            }));
+        let best = starts
+            .max_by_key(|start| grid.energized_tiles(start.clone()))
+            .ok_or_else(|| Error::solve(Self::DAY, "la grilla esta vacia"))?;
+        let title = format!(
+            "parte 2: desde {:?} hacia {:?}",
+            best.position, best.direction
+        );
+        Ok(vec![
+            grid.frame(
+                "parte 1".to_owned(),
+                Beam::new(Point::new(0, 0), Direction::East),
+            ),
+            grid.frame(title, best),
+        ])
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = Contraption;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Contraption, Error> {
+        Contraption::new(input)
+    }
+
+    fn part1(grid: &Contraption) -> Result<usize, Error> {
+        Ok(process_part1(grid))
+    }
+
+    fn part2(grid: &Contraption) -> Result<usize, Error> {
+        Ok(process_part2_par(grid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        let result = process_part1(&Contraption::new(input).unwrap());
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_energized() {
+        let grid = Contraption::new(include_str!("input_test.txt")).unwrap();
+        assert_eq!(grid.energized(Point::new(0, 0), Direction::East), 46);
+        assert_eq!(grid.energized(Point::new(3, 0), Direction::South), 51);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        let result = process_part2(&Contraption::new(input).unwrap());
+        assert_eq!(result, 51);
+    }
+    #[test]
+    fn test_part2_par() {
+        let input = include_str!("input_test.txt");
+        let result = process_part2_par(&Contraption::new(input).unwrap());
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day16>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = Contraption::new(include_str!("input_test.txt")).unwrap();
+        let frames = Day16::frames(&grid).unwrap();
+        let energized = |frame: &Frame| {
+            let points = grid.grid.points();
+            points.filter(|p| frame.color(*p) == Rgb::ENERGIZED).count()
+        };
+        assert_eq!(energized(&frames[0]), 46);
+        assert_eq!(energized(&frames[1]), 51);
+        assert!(frames[0].terminal().contains(">\x1b[0m"));
+    }
+}
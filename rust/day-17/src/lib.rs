@@ -0,0 +1,345 @@
+//! Day 17: Clumsy Crucible. The path through the city that loses the least
+//! heat, for a crucible that can't go straight for long and an ultra crucible
+//! that has to.
+
+#![warn(missing_docs)]
+
+use std::collections::BinaryHeap;
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Direction, Error, Point, Solution, Visualize,
+};
+
+/// The city blocks and the heat each one loses.
+#[derive(Debug)]
+pub struct City {
+    grid: Grid<usize>,
+}
+impl City {
+    /// Parses the map of single digit heat losses.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse(Day17::DAY, input, "0123456789")?;
+        let grid = grid.map(|c| *c as usize - '0' as usize);
+        Ok(Self { grid })
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        self.grid.contains(*point)
+    }
+
+    /// Heat lost entering the block at `point`.
+    pub fn cost(&self, point: &Point) -> usize {
+        self.grid[*point]
+    }
+
+    /// The bottom right block, where the crucible goes.
+    pub fn goal(&self) -> Point {
+        Point::new(self.grid.width() as i64 - 1, self.grid.height() as i64 - 1)
+    }
+
+    fn valid_nexts(&self, point: &Point) -> Vec<(Direction, Point)> {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| (direction, point.step(direction)))
+            .filter(|(_, next)| self.contains(next))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Node {
+    point: Point,
+    direction: Direction,
+    direction_count: usize,
+}
+
+impl Node {
+    fn new(point: Point, direction: Direction, direction_count: usize) -> Self {
+        Self {
+            point,
+            direction,
+            direction_count,
+        }
+    }
+
+    fn neighbors(node: &Node, grid: &City) -> Vec<Node> {
+        let mut neighbors = Vec::new();
+        for (direction, point) in grid.valid_nexts(&node.point) {
+            if direction == node.direction.opposite() {
+                continue;
+            } else if direction != node.direction {
+                neighbors.push(Node::new(point, direction, 1));
+            } else if node.direction_count < 3 {
+                neighbors.push(Node::new(point, direction, node.direction_count + 1));
+            }
+        }
+        neighbors
+    }
+    fn neighbor_part2(node: &Node, grid: &City) -> Vec<Node> {
+        let mut neighbors = Vec::new();
+        for (direction, point) in grid.valid_nexts(&node.point) {
+            if direction == node.direction.opposite() {
+                continue;
+            } else if direction != node.direction && node.direction_count >= 4 {
+                neighbors.push(Node::new(point, direction, 1));
+            } else if direction == node.direction && node.direction_count < 10 {
+                neighbors.push(Node::new(point, direction, node.direction_count + 1));
+            }
+        }
+        neighbors
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    node: Node,
+    cost: usize,
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.node.cmp(&other.node)) // reverse order from usual for min heap
+    }
+}
+
+fn dijkstra<F, G>(grid: &City, start: &Point, goal_fn: F, neighbor_fn: G) -> Option<usize>
+where
+    F: Fn(&Node) -> bool,
+    G: Fn(&Node, &City) -> Vec<Node>,
+{
+    // best cost by position, direction and steps in that direction
+    let (width, height) = (grid.grid.width(), grid.grid.height());
+    let mut distances = Grid::new(width, height, [[usize::MAX; 11]; 4]);
+    //start down and start right
+    distances[*start][Direction::South as usize][0] = 0;
+    distances[*start][Direction::East as usize][0] = 0;
+    let mut frontier = BinaryHeap::new();
+    //the two posibles start states
+    frontier.push(State {
+        node: Node::new(*start, Direction::South, 0),
+        cost: 0,
+    });
+    frontier.push(State {
+        node: Node::new(*start, Direction::East, 0),
+        cost: 0,
+    });
+    while let Some(State { node, cost }) = frontier.pop() {
+        if goal_fn(&node) {
+            return Some(cost);
+        }
+        for neighbor in neighbor_fn(&node, grid) {
+            let new_cost = cost + grid.cost(&neighbor.point);
+            let best = &mut distances[neighbor.point][neighbor.direction as usize];
+            let best = &mut best[neighbor.direction_count];
+            if new_cost >= *best {
+                continue;
+            }
+            *best = new_cost;
+            frontier.push(State {
+                node: neighbor,
+                cost: new_cost,
+            });
+        }
+    }
+    None
+}
+
+/// Least heat lost from the top left to the goal moving at most three blocks
+/// straight, with our own Dijkstra.
+pub fn process_part1(grid: &City) -> Option<usize> {
+    let start = Point::new(0, 0);
+    let goal = grid.goal();
+    let goal_fn = |node: &Node| node.point == goal;
+    let neighbor_fn = Node::neighbors;
+    dijkstra(grid, &start, goal_fn, neighbor_fn)
+}
+
+/// Least heat lost with the ultra crucible, which moves between four and ten
+/// blocks straight and only stops after four.
+pub fn procces_part2(grid: &City) -> Option<usize> {
+    let start = Point::new(0, 0);
+    let goal = grid.goal();
+    let goal_fn = |node: &Node| node.point == goal && node.direction_count >= 4;
+    let neighbor_fn = Node::neighbor_part2;
+    dijkstra(grid, &start, goal_fn, neighbor_fn)
+}
+
+/// [`process_part1`] with the pathfinding crate.
+pub fn using_pathfinding_part1(grid: &City) -> Option<usize> {
+    let start_south = Node::new(Point::new(0, 0), Direction::South, 0);
+    let goal = grid.goal();
+    let south_path = pathfinding::directed::dijkstra::dijkstra(
+        &start_south,
+        |node| {
+            Node::neighbors(node, grid)
+                .into_iter()
+                .map(|n| (n.clone(), grid.cost(&n.point)))
+        },
+        |node| node.point == goal,
+    );
+    let start_east = Node::new(Point::new(0, 0), Direction::East, 0);
+    let east_path = pathfinding::directed::dijkstra::dijkstra(
+        &start_east,
+        |node| {
+            Node::neighbors(node, grid)
+                .into_iter()
+                .map(|n| (n.clone(), grid.cost(&n.point)))
+        },
+        |node| node.point == goal,
+    );
+    [south_path, east_path].into_iter().flatten().map(|(_, cost)| cost).min()
+}
+
+/// [`procces_part2`] with the pathfinding crate.
+pub fn using_pathfinding_part2(grid: &City) -> Option<usize> {
+    let start_east = Node::new(Point::new(0, 0), Direction::East, 0);
+    let goal = grid.goal();
+    let esast_path = pathfinding::directed::dijkstra::dijkstra(
+        &start_east,
+        |node| {
+            Node::neighbor_part2(node, grid)
+                .into_iter()
+                .map(|n| (n.clone(), grid.cost(&n.point)))
+        },
+        |node| node.point == goal && node.direction_count >= 4,
+    );
+    let start_south = Node::new(Point::new(0, 0), Direction::South, 0);
+    let south_path = pathfinding::directed::dijkstra::dijkstra(
+        &start_south,
+        |node| {
+            Node::neighbor_part2(node, grid)
+                .into_iter()
+                .map(|n| (n.clone(), grid.cost(&n.point)))
+        },
+        |node| node.point == goal && node.direction_count >= 4,
+    );
+    [esast_path, south_path].into_iter().flatten().map(|(_, cost)| cost).min()
+}
+
+/// The [`Solution`] for day 17.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input<'a> = City;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<City, Error> {
+        City::new(input)
+    }
+
+    fn part1(grid: &City) -> Result<usize, Error> {
+        process_part1(grid).ok_or_else(|| Error::solve(Self::DAY, "no hay camino a la meta"))
+    }
+
+    fn part2(grid: &City) -> Result<usize, Error> {
+        procces_part2(grid).ok_or_else(|| Error::solve(Self::DAY, "no hay camino a la meta"))
+    }
+}
+
+/// Cheapest path to the goal with the pathfinding crate, starting east or
+/// south, and its heat loss.
+fn best_path(
+    grid: &City,
+    neighbor_fn: fn(&Node, &City) -> Vec<Node>,
+    goal_fn: impl Fn(&Node) -> bool,
+) -> Option<(Vec<Node>, usize)> {
+    [Direction::South, Direction::East]
+        .into_iter()
+        .filter_map(|direction| {
+            pathfinding::directed::dijkstra::dijkstra(
+                &Node::new(Point::new(0, 0), direction, 0),
+                |node| {
+                    neighbor_fn(node, grid)
+                        .into_iter()
+                        .map(|n| (n.clone(), grid.cost(&n.point)))
+                },
+                &goal_fn,
+            )
+        })
+        .min_by_key(|(_, cost)| *cost)
+}
+
+// The cheapest path of each part drawn with arrows over the heat loss map.
+impl Visualize for Day17 {
+    fn frames(grid: &City) -> Result<Vec<Frame>, Error> {
+        let goal = grid.goal();
+        let digits = grid
+            .grid
+            .map(|c| char::from_digit(*c as u32, 10).unwrap_or('?'));
+        let paths = [
+            best_path(grid, Node::neighbors, |node| node.point == goal),
+            best_path(grid, Node::neighbor_part2, |node| {
+                node.point == goal && node.direction_count >= 4
+            }),
+        ];
+        let mut frames = Vec::new();
+        for (part, path) in paths.into_iter().enumerate() {
+            let (path, cost) =
+                path.ok_or_else(|| Error::solve(Self::DAY, "no hay camino a la meta"))?;
+            let arrows = path.iter().skip(1).map(|node| {
+                let arrow = match node.direction {
+                    Direction::North => '^',
+                    Direction::South => 'v',
+                    Direction::East => '>',
+                    Direction::West => '<',
+                };
+                (node.point, arrow)
+            });
+            let title = format!("parte {}: perdida de calor {}", part + 1, cost);
+            frames.push(Frame::new(title, &digits).glyphs(arrows.collect::<Vec<_>>(), Rgb::PATH));
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(process_part1(&City::new(input).unwrap()), Some(102));
+    }
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(procces_part2(&City::new(input).unwrap()), Some(94));
+    }
+    #[test]
+    fn test_pathfinding() {
+        let grid = City::new(include_str!("input_test.txt")).unwrap();
+        assert_eq!(using_pathfinding_part1(&grid), Some(102));
+        assert_eq!(using_pathfinding_part2(&grid), Some(94));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day17>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = City::new(include_str!("input_test.txt")).unwrap();
+        let frames = Day17::frames(&grid).unwrap();
+        assert_eq!(frames[0].title, "parte 1: perdida de calor 102");
+        assert_eq!(frames[1].title, "parte 2: perdida de calor 94");
+        assert_eq!(frames[0].color(grid.goal()), Rgb::PATH);
+        assert_ne!(frames[0].color(Point::new(0, 0)), Rgb::PATH);
+    }
+}
@@ -0,0 +1,343 @@
+//! Day 18: Lavaduct Lagoon. A dig plan for a trench and how much lava the
+//! lagoon it encloses holds, read from the plan or from its colours.
+
+#![warn(missing_docs)]
+
+use std::fmt;
+
+use aoc_core::{
+    grid::Grid,
+    parse,
+    render::{Frame, Rgb},
+    Error, Solution, Visualize,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, digit1, hex_digit1, space1},
+    combinator::map_res,
+    sequence::delimited,
+    IResult, Parser,
+};
+
+#[cfg(test)]
+mod reference;
+
+/// Where an instruction digs, `U`, `D`, `L` or `R` in the plan.
+#[derive(Debug)]
+pub enum Direction {
+    /// `U`.
+    Up,
+    /// `D`.
+    Down,
+    /// `L`.
+    Left,
+    /// `R`.
+    Right,
+}
+/// One line of the dig plan, like `R 6 (#70c710)`.
+#[derive(Debug)]
+pub struct Instruction<'a> {
+    /// Where to dig.
+    pub direction: Direction,
+    /// How many cubes.
+    pub distance: isize,
+    /// The hex colour without its `#`, the real instruction in part 2.
+    pub color: &'a str,
+}
+
+impl Instruction<'_> {
+    /// The instruction hidden in the colour: five hex digits of distance and
+    /// the direction, 0 to 3 for right, down, left and up.
+    pub fn decoded(&self) -> Result<Instruction<'_>, Error> {
+        color_to_instruction(self.color)
+    }
+}
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{} {} (#{})", direction, self.distance, self.color)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+    fn manhattan_distance(&self, other: &Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction<'_>> {
+    let (input, direction) = alt((
+        complete::char('U').map(|_| Direction::Up),
+        complete::char('D').map(|_| Direction::Down),
+        complete::char('L').map(|_| Direction::Left),
+        complete::char('R').map(|_| Direction::Right),
+    ))(input)?;
+    let (input, distance) = delimited(space1, map_res(digit1, str::parse), space1)(input)?;
+    let (input, color) = delimited(tag("(#"), hex_digit1, complete::char(')'))(input)?;
+    Ok((
+        input,
+        Instruction {
+            direction,
+            distance,
+            color,
+        },
+    ))
+}
+
+//internal area
+fn sholace_formula(points: &[Point]) -> isize {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(p1, p2)| p1.x * p2.y - p2.x * p1.y)
+        .sum::<isize>()
+        .abs()
+        / 2
+}
+
+//A = i +b/2 -1 ==> i = A - b/2 +1 ,
+//this gives te internal points, we need to add the perimeter points
+//then wich is the sum of the manhatan distance of the points, the perimeter (b)
+// total_points = A -b/2 +1 + b = A + b/2 +1
+//Pick's theorem only holds for a closed loop, and if the trench touches itself
+//the cubes it closes off between the touching sides are not counted
+fn area(points: &[Point]) -> Result<isize, Error> {
+    if points.first() != points.last() {
+        return Err(Error::solve(Day18::DAY, "el camino no vuelve al inicio"));
+    }
+    let internal_area = sholace_formula(points);
+    let perimeter = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(p1, p2)| p1.manhattan_distance(p2))
+        .sum::<isize>();
+    Ok(internal_area + perimeter / 2 + 1)
+}
+
+/// Cubes of lava the lagoon dug by `instructions` holds, counting the trench.
+/// The plan has to end where it started.
+pub fn lagoon_area(instructions: &[Instruction]) -> Result<isize, Error> {
+    area(&build_points_part1(instructions))
+}
+
+fn build_points_part1(instructions: &[Instruction]) -> Vec<Point> {
+    let mut points = Vec::new();
+    let mut current_point = Point::new(0, 0);
+    points.push(current_point);
+    instructions.iter().for_each(|instruction| {
+        let mut new_point = current_point;
+        match instruction.direction {
+            Direction::Up => new_point.y += instruction.distance,
+            Direction::Down => new_point.y -= instruction.distance,
+            Direction::Left => new_point.x -= instruction.distance,
+            Direction::Right => new_point.x += instruction.distance,
+        }
+        points.push(new_point);
+        current_point = new_point;
+    });
+    points
+}
+
+fn color_to_instruction(color: &str) -> Result<Instruction<'_>, Error> {
+    let invalid = || Error::solve(Day18::DAY, format!("color invalido #{}", color));
+    let (distance, direction) = color.split_at(color.len() - 1);
+    let direction = match direction {
+        "3" => Direction::Up,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "0" => Direction::Right,
+        _ => return Err(invalid()),
+    };
+    let distance = isize::from_str_radix(distance, 16).map_err(|_| invalid())?;
+    Ok(Instruction {
+        direction,
+        distance,
+        color,
+    })
+}
+
+/// Parses the dig plan and writes it back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let instructions = Day18::parse(input)?;
+    Ok(instructions.iter().map(|i| format!("{}\n", i)).collect())
+}
+
+/// The [`Solution`] for day 18.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input<'a> = Vec<Instruction<'a>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction<'_>>, Error> {
+        parse::lines(Self::DAY, input, parse_instruction)
+    }
+
+    fn part1(instructions: &Vec<Instruction<'_>>) -> Result<isize, Error> {
+        lagoon_area(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction<'_>>) -> Result<isize, Error> {
+        let instructions = instructions
+            .iter()
+            .map(Instruction::decoded)
+            .collect::<Result<Vec<Instruction>, Error>>()?;
+        lagoon_area(&instructions)
+    }
+}
+
+/// The instruction's colour, for the trench it digs.
+fn rgb(color: &str) -> Rgb {
+    match u32::from_str_radix(color, 16) {
+        Ok(rgb) if color.len() == 6 => Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8),
+        _ => Rgb::PATH,
+    }
+}
+
+// Part 1's lagoon cube by cube: the trench in the colours of the plan and
+// what it encloses, found flooding the outside from a margin around it.
+impl Visualize for Day18 {
+    fn frames(instructions: &Vec<Instruction<'_>>) -> Result<Vec<Frame>, Error> {
+        let area = Self::part1(instructions)?;
+        let mut current = Point::new(0, 0);
+        let mut trench = vec![(current, Rgb::START)];
+        for instruction in instructions {
+            let (dx, dy) = match instruction.direction {
+                Direction::Up => (0, 1),
+                Direction::Down => (0, -1),
+                Direction::Left => (-1, 0),
+                Direction::Right => (1, 0),
+            };
+            for _ in 0..instruction.distance {
+                current = Point::new(current.x + dx, current.y + dy);
+                trench.push((current, rgb(instruction.color)));
+            }
+        }
+        let min_x = trench.iter().map(|(p, _)| p.x).min().unwrap_or(0) - 1;
+        let max_x = trench.iter().map(|(p, _)| p.x).max().unwrap_or(0) + 1;
+        let min_y = trench.iter().map(|(p, _)| p.y).min().unwrap_or(0) - 1;
+        let max_y = trench.iter().map(|(p, _)| p.y).max().unwrap_or(0) + 1;
+        // up in the plan is up on the screen
+        let cell = |p: &Point| aoc_core::Point::new((p.x - min_x) as i64, (max_y - p.y) as i64);
+        let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+
+        let mut lagoon = Grid::new(width, height, '.');
+        for (point, _) in &trench {
+            lagoon[cell(point)] = '#';
+        }
+        let mut outside = Grid::new(width, height, false);
+        let mut pending = vec![aoc_core::Point::new(0, 0)];
+        while let Some(point) = pending.pop() {
+            if lagoon[point] == '.' && !outside[point] {
+                outside[point] = true;
+                pending.extend(lagoon.neighbors(point));
+            }
+        }
+        let inside = lagoon
+            .iter()
+            .filter(|(p, c)| **c == '.' && !outside[*p])
+            .map(|(p, _)| p);
+
+        let title = format!("parte 1: {} metros cubicos", area);
+        let mut frame = Frame::new(title, &lagoon).overlay(inside.collect::<Vec<_>>(), Rgb::INSIDE);
+        for (point, color) in trench.iter().rev() {
+            frame = frame.overlay([cell(point)], *color);
+        }
+        Ok(vec![frame])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day18::part1(&Day18::parse(input).unwrap()).unwrap(), 62);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(
+            Day18::part2(&Day18::parse(input).unwrap()).unwrap(),
+            952408144115
+        );
+    }
+
+    #[test]
+    fn test_decoded() {
+        let instructions = Day18::parse("R 6 (#70c710)").unwrap();
+        assert_eq!(
+            instructions[0].decoded().unwrap().to_string(),
+            "R 461937 (#70c710)"
+        );
+        let square = Day18::parse("R 2 (#000000)\nD 2 (#000000)\nL 2 (#000000)\nU 2 (#000000)");
+        assert_eq!(lagoon_area(&square.unwrap()), Ok(9));
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day18::parse("R 6 (#70c710)\nD x (#0dc571)").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        let instructions = Day18::parse("R 6 (#70c717)").unwrap();
+        let error = Day18::part2(&instructions).unwrap_err();
+        assert_eq!(error.message, "color invalido #70c717");
+        let error = Day18::part1(&instructions).unwrap_err();
+        assert_eq!(error.message, "el camino no vuelve al inicio");
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "R 06 (#70c710)\nD  5 (#0dc571)";
+        assert_eq!(rewrite(input).unwrap(), "R 6 (#70c710)\nD 5 (#0dc571)\n");
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day18>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let instructions = Day18::parse(include_str!("input_test.txt")).unwrap();
+        let frame = Day18::frames(&instructions).unwrap().remove(0);
+        assert_eq!(frame.title, "parte 1: 62 metros cubicos");
+        assert_eq!((frame.width(), frame.height()), (9, 12));
+        let points = (0..9).flat_map(|x| (0..12).map(move |y| aoc_core::Point::new(x, y)));
+        let colors = points.map(|p| frame.color(p)).collect::<Vec<_>>();
+        assert_eq!(
+            colors.iter().filter(|c| **c == Rgb::INSIDE).count(),
+            62 - 38
+        );
+        assert_eq!(
+            colors
+                .iter()
+                .filter(|c| **c == Rgb(0x70, 0xc7, 0x10))
+                .count(),
+            6
+        );
+        assert_eq!(frame.color(aoc_core::Point::new(1, 1)), Rgb::START);
+    }
+}
@@ -0,0 +1,64 @@
+//! Digs the trench one cube at a time and floods the outside, to check the
+//! shoelace formula and Pick's theorem against.
+
+use std::collections::HashSet;
+
+use aoc_core::Solution;
+use aoc_gen::day18::{generate, Config};
+use proptest::prelude::*;
+
+use crate::{Day18, Direction, Instruction, Point};
+
+/// Cubes of lava the lagoon holds, the trench and everything it encloses.
+fn lagoon(instructions: &[Instruction]) -> usize {
+    let mut current = Point::new(0, 0);
+    let mut trench = HashSet::from([current]);
+    for instruction in instructions {
+        let (dx, dy) = match instruction.direction {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        for _ in 0..instruction.distance {
+            current = Point::new(current.x + dx, current.y + dy);
+            trench.insert(current);
+        }
+    }
+    // one cube of margin so the outside is connected
+    let min_x = trench.iter().map(|p| p.x).min().unwrap_or(0) - 1;
+    let max_x = trench.iter().map(|p| p.x).max().unwrap_or(0) + 1;
+    let min_y = trench.iter().map(|p| p.y).min().unwrap_or(0) - 1;
+    let max_y = trench.iter().map(|p| p.y).max().unwrap_or(0) + 1;
+    let start = Point::new(min_x, min_y);
+    let mut outside = HashSet::from([start]);
+    let mut pending = vec![start];
+    while let Some(point) = pending.pop() {
+        for (dx, dy) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let next = Point::new(point.x + dx, point.y + dy);
+            let inside_box = (min_x..=max_x).contains(&next.x) && (min_y..=max_y).contains(&next.y);
+            if inside_box && !trench.contains(&next) && outside.insert(next) {
+                pending.push(next);
+            }
+        }
+    }
+    let total = (max_x - min_x + 1) * (max_y - min_y + 1);
+    total as usize - outside.len()
+}
+
+#[test]
+fn test_lagoon() {
+    let instructions = Day18::parse(include_str!("input_test.txt")).unwrap();
+    assert_eq!(lagoon(&instructions), 62);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_area(seed: u64, size in 1..8usize, max_step in 1..6i64) {
+        let input = generate(&mut aoc_gen::rng(seed), &Config { size, max_step });
+        let instructions = Day18::parse(&input).unwrap();
+        prop_assert_eq!(Day18::part1(&instructions).unwrap(), lagoon(&instructions) as isize);
+    }
+}
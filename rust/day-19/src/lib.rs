@@ -0,0 +1,507 @@
+//! Day 19: Aplenty. Workflows of rules that send machine parts on to other
+//! workflows until they are accepted or rejected.
+
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt};
+
+use aoc_core::{parse, Error, Solution};
+
+#[cfg(test)]
+mod reference;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, one_of},
+    combinator::{map, map_res},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+
+/// A machine part with its four ratings.
+#[derive(Debug)]
+pub struct Part {
+    x: isize,
+    m: isize,
+    a: isize,
+    s: isize,
+}
+impl Part {
+    /// A part rated `x`, `m`, `a` and `s`.
+    pub fn new(x: isize, m: isize, a: isize, s: isize) -> Self {
+        Part { x, m, a, s }
+    }
+
+    fn parse(input: &str) -> IResult<&str, Part> {
+        let (input, _) = tag("{x=")(input)?;
+        let (input, x) = map_res(digit1, str::parse)(input)?;
+        let (input, _) = tag(",m=")(input)?;
+        let (input, m) = map_res(digit1, str::parse)(input)?;
+        let (input, _) = tag(",a=")(input)?;
+        let (input, a) = map_res(digit1, str::parse)(input)?;
+        let (input, _) = tag(",s=")(input)?;
+        let (input, s) = map_res(digit1, str::parse)(input)?;
+        let (input, _) = tag("}")(input)?;
+        Ok((input, Part { x, m, a, s }))
+    }
+    fn set(&mut self, var: char, val: isize) {
+        match var {
+            'x' => self.x = val,
+            'm' => self.m = val,
+            'a' => self.a = val,
+            's' => self.s = val,
+            _ => unreachable!(),
+        }
+    }
+    /// The rating for category `var`, one of `xmas`.
+    ///
+    /// # Panics
+    ///
+    /// If `var` is another character.
+    pub fn get_value(&self, var: char) -> isize {
+        match var {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => unreachable!("Condition::parse solo acepta xmas"),
+        }
+    }
+    /// The four ratings added up.
+    pub fn rating(&self) -> isize {
+        self.x + self.m + self.a + self.s
+    }
+
+    /// Runs the part through the workflows from `in` until it is accepted or
+    /// rejected.
+    pub fn process(&self, workflows: &Workflows) -> Result<Destination, Error> {
+        let mut current_workflow = workflow(workflows, "in")?; //start
+        loop {
+            match current_workflow.evaluate(self)? {
+                Destination::Accept => return Ok(Destination::Accept),
+                Destination::Reject => return Ok(Destination::Reject),
+                Destination::Workflow(name) => {
+                    current_workflow = workflow(workflows, &name)?;
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
+/// A comparison of one category of a part against a value. The input only
+/// has `<` and `>`, the other two are their opposites.
+#[derive(Debug, Clone, Copy)]
+pub enum Condition {
+    /// `x<10`.
+    LessThan(char, isize),
+    /// `x>10`.
+    GreaterThan(char, isize),
+    /// `x<=10`.
+    LessThanEqual(char, isize),
+    /// `x>=10`.
+    GreaterThanEqual(char, isize),
+}
+
+impl Condition {
+    fn parse(input: &str) -> IResult<&str, Condition> {
+        let (input, (var, op, val)) =
+            tuple((one_of("xmsa"), one_of("<>"), map_res(digit1, str::parse)))(input)?;
+        let cond = match op {
+            '<' => Condition::LessThan(var, val),
+            _ => Condition::GreaterThan(var, val),
+        };
+        Ok((input, cond))
+    }
+    /// Whether `part` meets the condition.
+    pub fn evaluate(&self, part: &Part) -> bool {
+        match self {
+            Condition::LessThan(var, val) => part.get_value(*var) < *val,
+            Condition::GreaterThan(var, val) => part.get_value(*var) > *val,
+            Condition::LessThanEqual(var, val) => part.get_value(*var) <= *val,
+            Condition::GreaterThanEqual(var, val) => part.get_value(*var) >= *val,
+        }
+    }
+
+    /// The condition parts that don't meet this one meet.
+    pub fn opposite(&self) -> Condition {
+        match self {
+            Condition::LessThan(var, val) => Condition::GreaterThanEqual(*var, *val),
+            Condition::GreaterThan(var, val) => Condition::LessThanEqual(*var, *val),
+            Condition::LessThanEqual(var, val) => Condition::GreaterThan(*var, *val),
+            Condition::GreaterThanEqual(var, val) => Condition::LessThan(*var, *val),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::LessThan(var, val) => write!(f, "{}<{}", var, val),
+            Condition::GreaterThan(var, val) => write!(f, "{}>{}", var, val),
+            Condition::LessThanEqual(var, val) => write!(f, "{}<={}", var, val),
+            Condition::GreaterThanEqual(var, val) => write!(f, "{}>={}", var, val),
+        }
+    }
+}
+
+/// Where a rule sends a part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Destination {
+    /// `A`, the part is accepted.
+    Accept,
+    /// `R`, the part is rejected.
+    Reject,
+    /// On to the workflow with this name.
+    Workflow(String),
+}
+
+impl Destination {
+    fn parse(input: &str) -> IResult<&str, Destination> {
+        let (input, destination) = alt((tag("A"), tag("R"), alpha1))(input)?;
+        let destination = match destination {
+            "A" => Destination::Accept,
+            "R" => Destination::Reject,
+            s => Destination::Workflow(s.to_string()),
+        };
+        Ok((input, destination))
+    }
+}
+
+impl fmt::Display for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Destination::Accept => write!(f, "A"),
+            Destination::Reject => write!(f, "R"),
+            Destination::Workflow(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// One rule of a workflow.
+#[derive(Debug)]
+pub enum Rule {
+    /// `x<10:A`, parts meeting the condition go to the destination.
+    Evaluation(Condition, Destination),
+    /// The last rule, every part that gets to it goes to the destination.
+    Fallthrough(Destination),
+}
+
+impl Rule {
+    fn parse(input: &str) -> IResult<&str, Rule> {
+        alt((
+            map(
+                tuple((Condition::parse, tag(":"), Destination::parse)),
+                |(c, _, d)| Rule::Evaluation(c, d),
+            ),
+            map(Destination::parse, Rule::Fallthrough),
+        ))(input)
+    }
+    /// Where the rule sends `part`, if it applies to it.
+    pub fn evaluate(&self, part: &Part) -> Option<Destination> {
+        match self {
+            Rule::Evaluation(cond, dest) => {
+                if cond.evaluate(part) {
+                    Some(dest.clone())
+                } else {
+                    None
+                }
+            }
+            Rule::Fallthrough(dest) => Some(dest.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Evaluation(cond, dest) => write!(f, "{}:{}", cond, dest),
+            Rule::Fallthrough(dest) => write!(f, "{}", dest),
+        }
+    }
+}
+
+/// A named list of rules, tried in order until one applies.
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+/// Every workflow by name, parts start at `in`.
+pub type Workflows = HashMap<String, Workflow>;
+
+impl Workflow {
+    /// A workflow called `name` with `rules`.
+    pub fn new(name: impl Into<String>, rules: Vec<Rule>) -> Self {
+        Workflow {
+            name: name.into(),
+            rules,
+        }
+    }
+
+    /// The workflow's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The workflow's rules, in order.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    fn parse(input: &str) -> IResult<&str, Workflow> {
+        let (input, name) = alpha1(input)?;
+        let name = name.to_string();
+        let (input, _) = tag("{")(input)?;
+        let (input, rules) = separated_list1(tag(","), Rule::parse)(input)?;
+        let (input, _) = tag("}")(input)?;
+        Ok((input, Workflow { name, rules }))
+    }
+
+    /// Where the first rule that applies sends `part`, an error if none does.
+    pub fn evaluate(&self, part: &Part) -> Result<Destination, Error> {
+        self.rules
+            .iter()
+            .find_map(|r| r.evaluate(part))
+            .ok_or_else(|| {
+                let message = format!("ninguna regla de {} acepta la pieza", self.name);
+                Error::solve(Day19::DAY, message)
+            })
+    }
+}
+
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules = self.rules.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+        write!(f, "{}{{{}}}", self.name, rules.join(","))
+    }
+}
+
+fn workflow<'a>(workflows: &'a Workflows, name: &str) -> Result<&'a Workflow, Error> {
+    workflows
+        .get(name)
+        .ok_or_else(|| Error::solve(Day19::DAY, format!("no existe el workflow {}", name)))
+}
+
+fn generate_paths(
+    workflows: &Workflows,
+    current: &str,
+    paretns: &[Condition],
+) -> Result<Vec<Vec<Condition>>, Error> {
+    let mut paths = Vec::new();
+    let workflow = workflow(workflows, current)?;
+    let mut previus_condition = Vec::new();
+    for rule in &workflow.rules {
+        let mut new_parents = paretns.to_vec();
+        new_parents.extend(previus_condition.clone());
+        match rule {
+            Rule::Evaluation(cond, dest) => {
+                new_parents.push(*cond);
+                previus_condition.push(cond.opposite());
+                match dest {
+                    Destination::Accept => {
+                        paths.push(new_parents);
+                    }
+                    Destination::Reject => {}
+                    Destination::Workflow(name) => {
+                        paths.extend(generate_paths(workflows, name, &new_parents)?);
+                    }
+                }
+            }
+            Rule::Fallthrough(dest) => match dest {
+                Destination::Accept => {
+                    paths.push(new_parents);
+                }
+                Destination::Reject => {}
+                Destination::Workflow(name) => {
+                    paths.extend(generate_paths(workflows, name, &new_parents)?);
+                }
+            },
+        }
+    }
+
+    Ok(paths)
+}
+
+fn calculate_possible_combinations(path: &[Condition]) -> isize {
+    // Track our minimum and maximum values.
+    let mut min_part = Part {
+        x: 1,
+        m: 1,
+        a: 1,
+        s: 1,
+    };
+    let mut max_part = Part {
+        x: 4000,
+        m: 4000,
+        a: 4000,
+        s: 4000,
+    };
+
+    for condition in path {
+        match condition {
+            Condition::LessThan(var, val) => {
+                max_part.set(*var, max_part.get_value(*var).min(val - 1));
+            }
+            Condition::LessThanEqual(var, val) => {
+                max_part.set(*var, max_part.get_value(*var).min(*val));
+            }
+            Condition::GreaterThan(var, val) => {
+                min_part.set(*var, min_part.get_value(*var).max(val + 1));
+            }
+            Condition::GreaterThanEqual(var, val) => {
+                min_part.set(*var, min_part.get_value(*var).max(*val));
+            }
+        }
+    }
+
+    //contradicting conditions leave an empty range, two of them would
+    //multiply into a positive count
+    let len = |var| (max_part.get_value(var) - min_part.get_value(var) + 1).max(0);
+    len('x') * len('m') * len('a') * len('s')
+}
+
+/// How many parts with every rating from 1 to 4000 the workflows accept.
+pub fn accepted_combinations(workflows: &Workflows) -> Result<isize, Error> {
+    let paths = generate_paths(workflows, "in", &[])?;
+    Ok(paths
+        .iter()
+        .map(|p| calculate_possible_combinations(p))
+        .sum())
+}
+
+/// Parses the workflows and parts and writes them back, for the fuzz
+/// targets. Workflows come out sorted by name.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let (parts, workflows) = Day19::parse(input)?;
+    let mut workflows = workflows.values().collect::<Vec<_>>();
+    workflows.sort_by(|a, b| a.name.cmp(&b.name));
+    let workflows = workflows.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    let parts = parts.iter().map(|p| format!("{}\n", p)).collect::<String>();
+    Ok(format!("{}\n\n{}", workflows.join("\n"), parts))
+}
+
+/// The [`Solution`] for day 19, the input is the parts and the workflows.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input<'a> = (Vec<Part>, Workflows);
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<(Vec<Part>, Workflows), Error> {
+        let (workflows, parts) = match parse::blocks(input)[..] {
+            [] => return Err(Error::parse(Self::DAY, "no hay workflows")),
+            [(_, workflows)] => (workflows, ""),
+            [(_, workflows), (_, parts)] => (workflows, parts),
+            [_, _, (line, _), ..] => {
+                return Err(Error::parse(Self::DAY, "texto de mas").at_line(line))
+            }
+        };
+        let workflows = workflows
+            .lines()
+            .map(|line| parse::within(Self::DAY, input, line, Workflow::parse))
+            .map(|w| w.map(|w| (w.name.clone(), w)))
+            .collect::<Result<_, _>>()?;
+        let parts = parts
+            .lines()
+            .map(|line| parse::within(Self::DAY, input, line, Part::parse))
+            .collect::<Result<_, _>>()?;
+        Ok((parts, workflows))
+    }
+
+    fn part1((parts, workflows): &(Vec<Part>, Workflows)) -> Result<isize, Error> {
+        let mut total = 0;
+        for part in parts {
+            if part.process(workflows)? == Destination::Accept {
+                total += part.rating();
+            }
+        }
+        Ok(total)
+    }
+
+    fn part2((_, workflows): &(Vec<Part>, Workflows)) -> Result<isize, Error> {
+        accepted_combinations(workflows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day19::part1(&Day19::parse(input).unwrap()).unwrap(), 19114);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(
+            Day19::part2(&Day19::parse(input).unwrap()).unwrap(),
+            167409079868000
+        );
+    }
+
+    #[test]
+    fn test_engine() {
+        let rule = |c, d| Rule::Evaluation(c, d);
+        let workflows = Workflows::from([(
+            "in".to_owned(),
+            Workflow::new(
+                "in",
+                vec![
+                    rule(Condition::LessThan('x', 11), Destination::Accept),
+                    Rule::Fallthrough(Destination::Reject),
+                ],
+            ),
+        )]);
+        assert_eq!(workflows["in"].rules().len(), 2);
+        let part = Part::new(10, 2, 3, 4);
+        assert_eq!(part.process(&workflows), Ok(Destination::Accept));
+        assert_eq!(
+            Part::new(11, 0, 0, 0).process(&workflows),
+            Ok(Destination::Reject)
+        );
+        assert_eq!(part.rating(), 19);
+        assert_eq!(
+            accepted_combinations(&workflows),
+            Ok(10 * 4000 * 4000 * 4000)
+        );
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day19::parse("in{x<10:A,R}\n\n{x=1,m=2,a=3}").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(13)));
+        let input = Day19::parse("in{x<10:px,R}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+        let error = Day19::part1(&input).unwrap_err();
+        assert_eq!(error.message, "no existe el workflow px");
+        assert!(Day19::part2(&input).is_err());
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "px{a<006:in,R}\nin{x>10:A,px}\n\n{x=1,m=2,a=3,s=04}";
+        let expected = "in{x>10:A,px}\npx{a<6:in,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        assert_eq!(rewrite(input).unwrap(), expected);
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day19>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,75 @@
+//! Sends one part from each block of ratings the workflows can't tell apart
+//! through them, to check the counting of part 2 against.
+
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_core::Solution;
+use aoc_gen::day19::{generate, Config};
+use proptest::prelude::*;
+
+use crate::{Condition, Day19, Destination, Part, Rule, Workflow};
+
+/// Combinations of ratings from 1 to 4000 the workflows accept.
+fn accepted(workflows: &HashMap<String, Workflow>) -> isize {
+    // the ratings where some condition changes its answer
+    let mut cuts = [(); 4].map(|_| BTreeSet::from([1, 4001]));
+    let categories = ['x', 'm', 'a', 's'];
+    for rule in workflows.values().flat_map(|workflow| &workflow.rules) {
+        if let Rule::Evaluation(condition, _) = rule {
+            let (var, cut) = match *condition {
+                Condition::LessThan(var, val) => (var, val),
+                Condition::GreaterThan(var, val) => (var, val + 1),
+                Condition::LessThanEqual(var, val) => (var, val + 1),
+                Condition::GreaterThanEqual(var, val) => (var, val),
+            };
+            let index = categories.iter().position(|&c| c == var).unwrap();
+            cuts[index].insert(cut.clamp(1, 4001));
+        }
+    }
+    let blocks = cuts.map(|cuts| {
+        let cuts = cuts.into_iter().collect::<Vec<_>>();
+        cuts.windows(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .collect::<Vec<_>>()
+    });
+    let mut total = 0;
+    for &(x, x_len) in &blocks[0] {
+        for &(m, m_len) in &blocks[1] {
+            for &(a, a_len) in &blocks[2] {
+                for &(s, s_len) in &blocks[3] {
+                    let part = Part { x, m, a, s };
+                    if part.process(workflows).unwrap() == Destination::Accept {
+                        total += x_len * m_len * a_len * s_len;
+                    }
+                }
+            }
+        }
+    }
+    total
+}
+
+#[test]
+fn test_accepted() {
+    let (_, workflows) = Day19::parse(include_str!("input_test.txt")).unwrap();
+    assert_eq!(accepted(&workflows), 167409079868000);
+}
+
+#[test]
+fn test_contradiction() {
+    // both x<10 and x>20 on the way to A, nothing gets there
+    let input = Day19::parse("in{x<10:px,R}\npx{x>20:A,m<5:A,R}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+    assert_eq!(accepted(&input.1), 9 * 4 * 4000 * 4000);
+    assert_eq!(Day19::part2(&input).unwrap(), 9 * 4 * 4000 * 4000);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_combinations(seed: u64, workflows in 1..6usize, max_rules in 1..4usize) {
+        let config = Config { workflows, parts: 0, max_rules };
+        let input = generate(&mut aoc_gen::rng(seed), &config);
+        let parsed = Day19::parse(&input).unwrap();
+        prop_assert_eq!(Day19::part2(&parsed).unwrap(), accepted(&parsed.1));
+    }
+}
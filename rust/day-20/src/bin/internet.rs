@@ -1,5 +1,7 @@
 use std::collections::{HashMap, VecDeque};
 
+use aoc_core::math::lcm_all;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -74,7 +76,7 @@ impl<'a> Machine<'a> {
     }
 }
 
-fn broadcast(input: &str) -> IResult<&str, Machine> {
+fn broadcast(input: &str) -> IResult<&str, Machine<'_>> {
     let (input, _) = tag("broadcaster -> ")(input)?;
     let (input, outputs) = separated_list1(tag(", "), alpha1)(input)?;
     Ok((
@@ -86,7 +88,7 @@ fn broadcast(input: &str) -> IResult<&str, Machine> {
         },
     ))
 }
-fn flip_flop(input: &str) -> IResult<&str, Machine> {
+fn flip_flop(input: &str) -> IResult<&str, Machine<'_>> {
     let (input, _) = tag("%")(input)?;
     let (input, name) = alpha1(input)?;
     let (input, _) = tag(" -> ")(input)?;
@@ -102,7 +104,7 @@ fn flip_flop(input: &str) -> IResult<&str, Machine> {
         },
     ))
 }
-fn conjunction(input: &str) -> IResult<&str, Machine> {
+fn conjunction(input: &str) -> IResult<&str, Machine<'_>> {
     let (input, _) = tag("&")(input)?;
     let (input, name) = alpha1(input)?;
     let (input, _) = tag(" -> ")(input)?;
@@ -118,7 +120,7 @@ fn conjunction(input: &str) -> IResult<&str, Machine> {
         },
     ))
 }
-fn parse(input: &str) -> IResult<&str, HashMap<&str, Machine>> {
+fn parse(input: &str) -> IResult<&str, HashMap<&str, Machine<'_>>> {
     let (input, machines) =
         separated_list1(line_ending, alt((broadcast, flip_flop, conjunction)))(input)?;
     Ok((
@@ -185,7 +187,7 @@ fn process(input: &str) -> Result<String, String> {
             });
         });
 
-    let mut lcms: Vec<usize> = vec![];
+    let mut lcms: Vec<u64> = vec![];
     for i in 0.. {
         if lcms.len() == 4 {
             break;
@@ -212,23 +214,9 @@ fn process(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(lcm(&lcms).to_string())
-}
-
-pub fn lcm(nums: &[usize]) -> usize {
-    if nums.len() == 1 {
-        return nums[0];
-    }
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-    a * b / gcd_of_two_numbers(a, b)
-}
-
-fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
-    }
-    gcd_of_two_numbers(b, a % b)
+    lcm_all(&lcms)
+        .map(|lcm| lcm.to_string())
+        .ok_or_else(|| "el mcm de los ciclos no entra en un u64".to_string())
 }
 
 fn main() {
@@ -0,0 +1,407 @@
+//! Day 20: Pulse Propagation. Flip-flops, conjunctions and a broadcaster
+//! passing high and low pulses every time the button is pressed.
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{math::lcm_all, parse, Error, Solution};
+
+#[cfg(test)]
+mod reference;
+
+trait HandleCommunication {
+    fn handle_comunication(&mut self, comunication: &Communication) -> Vec<Communication>;
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pulse {
+    High,
+    Low,
+}
+#[derive(Debug)]
+struct Communication {
+    source: String,
+    destination: String,
+    pulse: Pulse,
+}
+
+impl Communication {
+    fn new(source: String, destination: String, pulse: Pulse) -> Self {
+        Self {
+            source,
+            destination,
+            pulse,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    On,
+    Off,
+}
+
+#[derive(Debug)]
+struct FlipFlop {
+    state: State,
+    id: String,
+    destinations: Vec<String>,
+}
+
+impl FlipFlop {
+    fn new(id: String, destinations: Vec<String>) -> Self {
+        Self {
+            state: State::Off,
+            id,
+            destinations,
+        }
+    }
+}
+
+impl HandleCommunication for FlipFlop {
+    fn handle_comunication(&mut self, comunication: &Communication) -> Vec<Communication> {
+        match comunication.pulse {
+            Pulse::High => Vec::new(),
+            Pulse::Low => match self.state {
+                State::On => {
+                    self.state = State::Off;
+                    self.destinations
+                        .iter()
+                        .map(|destination| Communication {
+                            source: self.id.to_owned(),
+                            destination: destination.to_owned(),
+                            pulse: Pulse::Low,
+                        })
+                        .collect()
+                }
+                State::Off => {
+                    self.state = State::On;
+                    self.destinations
+                        .iter()
+                        .map(|destination| Communication {
+                            source: self.id.to_owned(),
+                            destination: destination.to_owned(),
+                            pulse: Pulse::High,
+                        })
+                        .collect()
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Broadcaster {
+    id: String,
+    destinations: Vec<String>,
+}
+
+impl Broadcaster {
+    fn new(id: String, destinations: Vec<String>) -> Self {
+        Self { id, destinations }
+    }
+}
+impl HandleCommunication for Broadcaster {
+    fn handle_comunication(&mut self, comunication: &Communication) -> Vec<Communication> {
+        self.destinations
+            .iter()
+            .map(|destination| Communication {
+                source: self.id.to_owned(),
+                destination: destination.to_owned(),
+                pulse: comunication.pulse,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Conjunction {
+    id: String,
+    destinations: Vec<String>,
+    inputs: HashMap<String, Pulse>,
+}
+
+impl Conjunction {
+    fn new(id: String, inputs: Vec<String>, destinations: Vec<String>) -> Self {
+        Self {
+            id,
+            destinations,
+            inputs: inputs
+                .into_iter()
+                .map(|input| (input.to_string(), Pulse::Low))
+                .collect(),
+        }
+    }
+}
+
+impl HandleCommunication for Conjunction {
+    fn handle_comunication(&mut self, comunication: &Communication) -> Vec<Communication> {
+        self.inputs
+            .entry(comunication.source.clone())
+            .and_modify(|pulse| {
+                *pulse = comunication.pulse;
+            });
+        let pulse_to_send = if self.inputs.values().all(|pulse| *pulse == Pulse::High) {
+            Pulse::Low
+        } else {
+            Pulse::High
+        };
+        self.destinations
+            .iter()
+            .map(|destination| Communication {
+                source: self.id.to_owned(),
+                destination: destination.to_owned(),
+                pulse: pulse_to_send,
+            })
+            .collect()
+    }
+}
+
+/// Module type (`b`, `%` or `&`), name and destinations.
+pub type Module = (char, String, Vec<String>);
+
+/// The modules wired together, each one with its current state.
+pub struct Configuration {
+    map: HashMap<String, Box<dyn HandleCommunication>>,
+    modules: HashMap<String, Vec<String>>,
+    conjunctions: HashSet<String>,
+}
+
+impl Configuration {
+    fn parse_module(input: &str) -> Result<Module, String> {
+        let (module, destinations) = input
+            .split_once(" -> ")
+            .ok_or("se esperaba `modulo -> destinos`")?;
+        let (type_, name) = match module.chars().next() {
+            Some('b') => ('b', module.to_owned()),
+            Some('%') => ('%', module[1..].to_owned()),
+            Some('&') => ('&', module[1..].to_owned()),
+            _ => return Err(format!("tipo de modulo desconocido {}", module)),
+        };
+        let destinations = destinations
+            .split(", ")
+            .map(|s| s.to_owned())
+            .collect::<Vec<_>>();
+        Ok((type_, name, destinations))
+    }
+
+    fn write_module((type_, name, destinations): &Module) -> String {
+        let module = match type_ {
+            'b' => name.to_owned(),
+            _ => format!("{}{}", type_, name),
+        };
+        format!("{} -> {}", module, destinations.join(", "))
+    }
+
+    /// Every module in its initial state: flip-flops off and conjunctions
+    /// remembering a low pulse from each input.
+    pub fn new(modules: &[Module]) -> Result<Configuration, Error> {
+        let mut map: HashMap<String, Box<dyn HandleCommunication>> = HashMap::new();
+        let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
+        for (_, name, destinations) in modules {
+            for destination in destinations {
+                inputs
+                    .entry(destination.to_string())
+                    .or_default()
+                    .push(name.to_string());
+            }
+        }
+        for (type_, name, destinations) in modules.iter().cloned() {
+            let module: Box<dyn HandleCommunication> = match type_ {
+                'b' => Box::new(Broadcaster::new(name.to_owned(), destinations)),
+                '%' => Box::new(FlipFlop::new(name.to_owned(), destinations)),
+                '&' => Box::new(Conjunction::new(
+                    name.to_owned(),
+                    inputs.get(&name).cloned().unwrap_or_default(),
+                    destinations,
+                )),
+                _ => {
+                    let message = format!("tipo de modulo desconocido {}", type_);
+                    return Err(Error::solve(Day20::DAY, message));
+                }
+            };
+            map.insert(name, module);
+        }
+        let conjunctions = modules
+            .iter()
+            .filter(|(type_, _, _)| *type_ == '&')
+            .map(|(_, name, _)| name.clone())
+            .collect();
+        let modules = modules
+            .iter()
+            .map(|(_, name, destinations)| (name.clone(), destinations.clone()))
+            .collect::<HashMap<_, _>>();
+        Ok(Configuration {
+            map,
+            modules,
+            conjunctions,
+        })
+    }
+
+    /// Presses the button once, returns how many high and low pulses were
+    /// sent.
+    pub fn press_button(&mut self) -> (usize, usize) {
+        let mut highs = 0;
+        let mut lows = 0;
+        let mut queue: VecDeque<Communication> = VecDeque::new();
+        queue.push_back(Communication::new(
+            "button".to_owned(),
+            "broadcaster".to_owned(),
+            Pulse::Low,
+        ));
+        while let Some(communication) = queue.pop_front() {
+            match communication.pulse {
+                Pulse::High => highs += 1,
+                Pulse::Low => lows += 1,
+            }
+            let module = match self.map.get_mut(&communication.destination) {
+                Some(m) => m,
+                None => continue,
+            };
+            let new_comunications = module.handle_comunication(&communication);
+            queue.extend(new_comunications);
+        }
+        (highs, lows)
+    }
+
+    fn solve_part_1(&mut self) -> usize {
+        let (highs, lows) = (0..1_000)
+            .map(|_| self.press_button())
+            .fold((0, 0), |(h1, l1), (h2, l2)| (h1 + h2, l1 + l2));
+        highs * lows
+    }
+
+    fn solve_part_2(&mut self) -> Result<u64, Error> {
+        //rx is connected to a conjuction, gf,
+        //witch is connected to 4 conjuctions qk, kr, kf and zs.
+        let final_node = "rx".to_string();
+        let penultimate_node = self
+            .modules
+            .iter()
+            .find_map(|(id, destinations)| destinations.contains(&final_node).then_some(id))
+            .ok_or_else(|| Error::solve(Day20::DAY, "ningun modulo envia pulsos a rx"))?;
+        //rx only gets a low pulse when all the inputs of the conjuction last sent high
+        if !self.conjunctions.contains(penultimate_node) {
+            let message = format!("{} no es una conjuncion", penultimate_node);
+            return Err(Error::solve(Day20::DAY, message));
+        }
+        let mut antepenultimate_nodes = self
+            .modules
+            .iter()
+            .filter_map(|(id, destinations)| destinations.contains(penultimate_node).then_some(id))
+            .collect::<Vec<_>>();
+        let expected = antepenultimate_nodes.len();
+        if expected == 0 {
+            let message = format!("ningun modulo envia pulsos a {}", penultimate_node);
+            return Err(Error::solve(Day20::DAY, message));
+        }
+        let mut lcm_tocalculate = Vec::new();
+        for i in 0.. {
+            if lcm_tocalculate.len() == expected {
+                break;
+            }
+            let mut queue: VecDeque<Communication> = VecDeque::new();
+            queue.push_back(Communication::new(
+                "button".to_owned(),
+                "broadcaster".to_owned(),
+                Pulse::Low,
+            ));
+            while let Some(communication) = queue.pop_front() {
+                if communication.pulse == Pulse::High {
+                    if let Some(index) = antepenultimate_nodes
+                        .iter()
+                        .position(|&id| *id == communication.source)
+                    {
+                        lcm_tocalculate.push(i + 1);
+                        antepenultimate_nodes.remove(index);
+                    }
+                }
+                let module = match self.map.get_mut(&communication.destination) {
+                    Some(m) => m,
+                    None => continue,
+                };
+                let new_comunications = module.handle_comunication(&communication);
+                queue.extend(new_comunications);
+            }
+        }
+        lcm_all(&lcm_tocalculate)
+            .ok_or_else(|| Error::solve(Day20::DAY, "el mcm de los ciclos no entra en un u64"))
+    }
+}
+
+/// Parses the modules and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let modules = Day20::parse(input)?;
+    Ok(modules
+        .iter()
+        .map(|m| format!("{}\n", Configuration::write_module(m)))
+        .collect())
+}
+
+/// The [`Solution`] for day 20, the input is the list of modules.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input<'a> = Vec<Module>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Module>, Error> {
+        parse::lines_with(Self::DAY, input, Configuration::parse_module)
+    }
+
+    // Both parts press the button on a freshly built configuration.
+    fn part1(modules: &Vec<Module>) -> Result<usize, Error> {
+        Ok(Configuration::new(modules)?.solve_part_1())
+    }
+
+    fn part2(modules: &Vec<Module>) -> Result<u64, Error> {
+        Configuration::new(modules)?.solve_part_2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("input_test.txt");
+        let modules = Day20::parse(input).unwrap();
+        assert_eq!(Day20::part1(&modules).unwrap(), 32000000);
+    }
+
+    #[test]
+    fn test_press_button() {
+        let modules = Day20::parse(include_str!("input_test.txt")).unwrap();
+        let mut configuration = Configuration::new(&modules).unwrap();
+        assert_eq!(configuration.press_button(), (4, 8));
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day20::parse("broadcaster -> a\n%a -> b\n#b -> a").unwrap_err();
+        assert_eq!(error.line, Some(3));
+        let modules = Day20::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(
+            Day20::part2(&modules).unwrap_err().message,
+            "ningun modulo envia pulsos a rx"
+        );
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "broadcaster -> a, b\n%a -> con\n&con -> output, ";
+        assert_eq!(rewrite(input).unwrap(), format!("{}\n", input));
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day20>(include_str!("input_test.txt"));
+    }
+}
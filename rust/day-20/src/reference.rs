@@ -0,0 +1,55 @@
+//! Presses the button until `rx` gets a low pulse, to check the LCM of part 2
+//! against.
+
+use std::collections::VecDeque;
+
+use aoc_core::Solution;
+use aoc_gen::day20::{generate, Config};
+use proptest::prelude::*;
+
+use crate::{Communication, Configuration, Day20, Module, Pulse};
+
+/// Presses until `rx` gets a low pulse, if that happens within `limit`.
+fn presses(modules: &[Module], limit: u64) -> Option<u64> {
+    let mut configuration = Configuration::new(modules).ok()?;
+    for press in 1..=limit {
+        let mut low_to_rx = false;
+        let mut queue = VecDeque::from([Communication::new(
+            "button".to_owned(),
+            "broadcaster".to_owned(),
+            Pulse::Low,
+        )]);
+        while let Some(communication) = queue.pop_front() {
+            low_to_rx |= communication.destination == "rx" && communication.pulse == Pulse::Low;
+            if let Some(module) = configuration.map.get_mut(&communication.destination) {
+                queue.extend(module.handle_comunication(&communication));
+            }
+        }
+        if low_to_rx {
+            return Some(press);
+        }
+    }
+    None
+}
+
+#[test]
+fn test_presses() {
+    let modules = Day20::parse("broadcaster -> a\n%a -> b\n%b -> rx").unwrap();
+    assert_eq!(presses(&modules, 10), Some(4));
+    assert_eq!(
+        Day20::part2(&modules).unwrap_err().message,
+        "b no es una conjuncion"
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_counters(seed: u64, counters in 1..4usize, bits in 2..6u32) {
+        let input = generate(&mut aoc_gen::rng(seed), &Config { counters, bits });
+        let modules = Day20::parse(&input).unwrap();
+        let expected = Day20::part2(&modules).unwrap();
+        prop_assert_eq!(presses(&modules, expected), Some(expected));
+    }
+}
@@ -0,0 +1,245 @@
+//! Day 21: Step Counter. Counting the garden plots an elf can end on after
+//! an exact number of steps, in the garden and in its infinite copies.
+
+#![warn(missing_docs)]
+
+use std::collections::VecDeque;
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Error, Point, Solution, Visualize,
+};
+
+#[cfg(test)]
+mod reference;
+
+/// The garden: where the rocks are and where the elf starts.
+#[derive(Debug)]
+pub struct Garden {
+    rocks: Grid<bool>,
+    start: Point,
+}
+
+impl Garden {
+    /// Parses a map of plots `.`, rocks `#` and a single start `S`.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse(Day21::DAY, input, ".#S")?;
+        let mut starts = grid.iter().filter(|(_, c)| **c == 'S').map(|(p, _)| p);
+        let start = starts
+            .next()
+            .ok_or_else(|| Error::parse(Day21::DAY, "no hay punto de inicio"))?;
+        if let Some(point) = starts.next() {
+            return Err(Error::parse(Day21::DAY, "hay mas de un punto de inicio")
+                .at(point.y as usize + 1, point.x as usize + 1));
+        }
+        let rocks = grid.map(|c| *c == '#');
+        Ok(Self { rocks, start })
+    }
+
+    /// `true` for the cells with a rock.
+    pub fn rocks(&self) -> &Grid<bool> {
+        &self.rocks
+    }
+
+    /// Where the elf starts.
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    fn neigbours(&self, point: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.rocks.neighbors(*point).filter(|p| !self.rocks[*p])
+    }
+
+    /// The plots the elf can end on after exactly `steps` steps.
+    //a plot reached in fewer steps can be reached again going back and forth
+    pub fn solve_part_1(&self, steps: usize) -> usize {
+        self.reach().count(steps)
+    }
+
+    fn calculate_distances(&self) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.rocks.width(), self.rocks.height(), None);
+        let mut frontier = VecDeque::new();
+        frontier.push_back((self.start, 0));
+        while let Some((point, distance)) = frontier.pop_front() {
+            if distances[point].is_none() {
+                distances[point] = Some(distance);
+                for neighbour in self.neigbours(&point) {
+                    frontier.push_back((neighbour, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    fn reach(&self) -> Reach {
+        let mut by_parity = [Vec::new(), Vec::new()];
+        for (_, distance) in self.calculate_distances().iter() {
+            let Some(distance) = *distance else {
+                continue;
+            };
+            by_parity[distance % 2].push(distance);
+        }
+        by_parity
+            .iter_mut()
+            .for_each(|distances| distances.sort_unstable());
+        Reach { by_parity }
+    }
+
+    /// Like [`Garden::solve_part_1`] with the garden repeated forever in every
+    /// direction. Only works for square gardens of odd size with the start in
+    /// the middle, no rocks on the edges or on the row and column of the
+    /// start, and `steps` a whole number of gardens past the edge of the
+    /// first one.
+    //magic numbers come from https://www.youtube.com/watch?v=KOHYAlsOwOM
+    //for the real input numbers of steps is 26501365 = 202300*131 + 65
+    //65 = 131/2
+    //131 is the sice of the gird, 202300 is the number of grids we can reach
+    //of thath 202300 some are cover completely and some are not
+    //thats the corners, depeding on the parity of the ditsance we have to add or substract
+    //copies next to each other have opposite parity, the parity of the
+    //outermost copies is counted (n+1)^2 times and the other one n^2 times
+    pub fn solve_part_2(&self, steps: usize) -> Result<usize, Error> {
+        let size = self.rocks.width();
+        let half = size / 2;
+        let free = |i: i64| i == 0 || i == half as i64 || i == size as i64 - 1;
+        if self.rocks.height() != size
+            || size.is_multiple_of(2)
+            || self.start != Point::new(half as i64, half as i64)
+            || self
+                .rocks
+                .iter()
+                .any(|(p, &rock)| rock && (free(p.x) || free(p.y)))
+        {
+            return Err(Error::solve(
+                Day21::DAY,
+                "la formula supone una grilla cuadrada con el inicio en el centro y sin rocas \
+                 en los bordes ni en la fila y columna del inicio",
+            ));
+        }
+        if steps < half || !(steps - half).is_multiple_of(size) {
+            let message = format!("{} pasos no es {} mas un multiplo de {}", steps, half, size);
+            return Err(Error::solve(Day21::DAY, message));
+        }
+        let count = (steps - half) / size;
+        let distances = self.calculate_distances();
+        //plots with the parity of steps and the others, and those in the corners
+        let (same, other, same_corners, other_corners) =
+            distances.iter().filter_map(|(_, distance)| *distance).fold(
+                (0_usize, 0_usize, 0_usize, 0_usize),
+                |(same, other, same_corners, other_corners), distance| {
+                    let corner = usize::from(distance > half);
+                    if distance % 2 == steps % 2 {
+                        (same + 1, other, same_corners + corner, other_corners)
+                    } else {
+                        (same, other + 1, same_corners, other_corners + corner)
+                    }
+                },
+            );
+        let (outer, inner, outer_corners, inner_corners) = if count.is_multiple_of(2) {
+            (same, other, same_corners, other_corners)
+        } else {
+            (other, same, other_corners, same_corners)
+        };
+        let total_outer = outer * (count + 1) * (count + 1); //outer*(n+1)^2
+        let total_inner = inner * (count * count); //inner*n^2
+        let total_outer_corners = outer_corners * (count + 1); //outer_corners*(n+1)
+        let total_inner_corners = inner_corners * count; //inner_corners*n
+        Ok(total_outer + total_inner - total_outer_corners + total_inner_corners)
+    }
+}
+
+//distances to the plots of the garden from the start
+struct Reach {
+    by_parity: [Vec<usize>; 2],
+}
+
+impl Reach {
+    //plots the walk can end on with steps left, it can only go back and forth
+    fn count(&self, steps: usize) -> usize {
+        self.by_parity[steps % 2].partition_point(|&distance| distance <= steps)
+    }
+}
+
+/// The [`Solution`] for day 21, the input is the garden.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input<'a> = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Garden, Error> {
+        Garden::new(input)
+    }
+
+    fn part1(garden: &Garden) -> Result<usize, Error> {
+        Ok(garden.solve_part_1(64))
+    }
+
+    fn part2(garden: &Garden) -> Result<usize, Error> {
+        garden.solve_part_2(26501365)
+    }
+}
+
+// Part 1 step by step, the plots the elf can be on after each one.
+impl Visualize for Day21 {
+    fn frames(garden: &Garden) -> Result<Vec<Frame>, Error> {
+        let mut map = garden.rocks.map(|rock| if *rock { '#' } else { '.' });
+        map[garden.start] = 'S';
+        let distances = garden.calculate_distances();
+        let frames = (0..=64).map(|steps| {
+            let plots = distances.iter().filter_map(|(point, distance)| {
+                distance
+                    .filter(|d| *d <= steps && d % 2 == steps % 2)
+                    .map(|_| point)
+            });
+            let plots = plots.collect::<Vec<_>>();
+            let title = format!("paso {}: {} parcelas", steps, plots.len());
+            Frame::new(title, &map).overlay(plots, Rgb::REACHABLE)
+        });
+        Ok(frames.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("input_test.txt");
+        let garden = Garden::new(input).unwrap();
+        assert_eq!(garden.solve_part_1(6), 16);
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Garden::new("...\n.S.\n..S").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+        assert!(Garden::new("...\n...").is_err());
+        let garden = Garden::new(include_str!("input_test.txt")).unwrap();
+        assert!(garden.solve_part_2(26501365).is_err());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day21>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let garden = Garden::new(include_str!("input_test.txt")).unwrap();
+        let frames = Day21::frames(&garden).unwrap();
+        assert_eq!(frames.len(), 65);
+        assert_eq!(frames[0].color(garden.start), Rgb::REACHABLE);
+        assert_eq!(frames[6].title, "paso 6: 16 parcelas");
+        assert_eq!(
+            frames[64].title,
+            format!("paso 64: {} parcelas", garden.solve_part_1(64))
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Walks the infinite garden one step at a time, to check the shortcuts of
+//! [`Garden`] on gardens small enough to walk.
+
+use std::collections::HashSet;
+
+use aoc_core::Point;
+use aoc_gen::day21::{generate, Config};
+use proptest::prelude::*;
+
+use crate::Garden;
+
+/// Plots reachable in exactly `steps` steps with the garden repeated in
+/// every direction.
+fn reachable(garden: &Garden, steps: usize) -> usize {
+    let mut current = HashSet::from([garden.start]);
+    for _ in 0..steps {
+        current = current
+            .iter()
+            .flat_map(Point::neighbors)
+            .filter(|point| {
+                let x = point.x.rem_euclid(garden.rocks.width() as i64);
+                let y = point.y.rem_euclid(garden.rocks.height() as i64);
+                !garden.rocks[Point::new(x, y)]
+            })
+            .collect();
+    }
+    current.len()
+}
+
+#[test]
+fn test_reachable() {
+    let garden = Garden::new(include_str!("input_test.txt")).unwrap();
+    assert_eq!(reachable(&garden, 6), 16);
+    assert_eq!(reachable(&garden, 100), 6536);
+}
+
+// rocks that touch can make a walk go around them, and then a plot of a
+// copy is closer from another of its corners than from the start, which
+// the formula of part 2 can't see. This keeps only the rocks with no other
+// rock kept around them.
+fn scattered(mut garden: Garden) -> Garden {
+    for point in garden.rocks.points() {
+        if garden.rocks[point] && garden.rocks.neighbors8(point).any(|p| garden.rocks[p]) {
+            garden.rocks[point] = false;
+        }
+    }
+    garden
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    // the walk can't leave a garden with the start in the middle
+    #[test]
+    fn test_solve_part_1(
+        seed: u64,
+        size in 1..15usize,
+        rocks in 0.0..0.4,
+        steps in 0..8usize,
+    ) {
+        let input = generate(&mut aoc_gen::rng(seed), &Config { size, rocks });
+        let garden = Garden::new(&input).unwrap();
+        let steps = steps.min(size / 2);
+        prop_assert_eq!(garden.solve_part_1(steps), reachable(&garden, steps));
+    }
+
+    #[test]
+    fn test_corner_formula(
+        seed: u64,
+        size in 1..15usize,
+        rocks in 0.0..0.4,
+        count in 0..5usize,
+    ) {
+        let input = generate(&mut aoc_gen::rng(seed), &Config { size, rocks });
+        let garden = scattered(Garden::new(&input).unwrap());
+        let steps = count * garden.rocks.width() + garden.rocks.width() / 2;
+        prop_assert_eq!(garden.solve_part_2(steps).unwrap(), reachable(&garden, steps));
+    }
+
+    #[test]
+    fn test_no_rocks(steps in 0..40usize) {
+        let garden = Garden::new(".....\n.....\n..S..\n.....\n.....").unwrap();
+        prop_assert_eq!(reachable(&garden, steps), (steps + 1) * (steps + 1));
+    }
+}
+
+#[test]
+fn test_solve_part_2() {
+    // with no rocks every plot at an even distance up to 26501365 is reached
+    let input = generate(
+        &mut aoc_gen::rng(0),
+        &Config {
+            size: 131,
+            rocks: 0.0,
+        },
+    );
+    let garden = Garden::new(&input).unwrap();
+    assert_eq!(garden.solve_part_2(26501365), Ok(26501366 * 26501366));
+    assert!(garden.solve_part_2(26501366).is_err());
+}
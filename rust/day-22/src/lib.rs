@@ -0,0 +1,262 @@
+//! Day 22: Sand Slabs. Letting falling bricks settle and working out which
+//! ones hold up the others.
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{parse, Error, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+impl Point {
+    fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { x, y, z }
+    }
+    fn parse(input: &str) -> Result<Self, String> {
+        let (x, y, z) = input
+            .split(',')
+            .map(|s| s.parse::<usize>())
+            .collect_tuple()
+            .ok_or_else(|| format!("se esperaba `x,y,z`: {}", input))?;
+        let numero = |n: Result<usize, _>| n.map_err(|_| format!("coordenada invalida: {}", input));
+        Ok(Point::new(numero(x)?, numero(y)?, numero(z)?))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Brick {
+    start: Point,
+    end: Point,
+    id: usize,
+}
+impl Brick {
+    fn new(start: Point, end: Point, id: usize) -> Self {
+        Self { start, end, id }
+    }
+
+    fn parse(input: &str, id: usize) -> Result<Self, String> {
+        let (start, end) = input
+            .split('~')
+            .collect_tuple()
+            .ok_or("se esperaba `inicio~fin`")?;
+        let start = Point::parse(start)?;
+        let end = Point::parse(end)?;
+        if start.x > end.x || start.y > end.y || start.z > end.z {
+            return Err("el ladrillo termina antes de empezar".to_owned());
+        }
+        if start.z == 0 {
+            return Err("el ladrillo atraviesa el piso".to_owned());
+        }
+        Ok(Brick::new(start, end, id))
+    }
+
+    fn intersecs_xy(&self, other: &Self) -> bool {
+        self.start.x <= other.end.x
+            && self.end.x >= other.start.x
+            && self.start.y <= other.end.y
+            && self.end.y >= other.start.y
+    }
+
+    fn hiegs_z_to_drop(&self, others: &[Self]) -> usize {
+        others
+            .iter()
+            .filter(|brick| self.intersecs_xy(brick) && brick.end.z < self.start.z)
+            .map(|brick| brick.end.z)
+            .max()
+            .unwrap_or(0)
+    }
+    fn ids_above(&self, others: &[Self]) -> Vec<usize> {
+        others
+            .iter()
+            .filter(|brick| self.intersecs_xy(brick) && brick.start.z == self.end.z + 1)
+            .map(|brick| brick.id)
+            .collect()
+    }
+    fn ids_under(&self, others: &[Self]) -> Vec<usize> {
+        others
+            .iter()
+            .filter(|brick| self.intersecs_xy(brick) && brick.end.z == self.start.z - 1)
+            .map(|brick| brick.id)
+            .collect()
+    }
+}
+
+/// The bricks as they were in the snapshot, lowest first.
+#[derive(Debug)]
+pub struct Wall {
+    bricks: Vec<Brick>,
+}
+
+impl Wall {
+    fn new(bricks: Vec<Brick>) -> Self {
+        Self { bricks }
+    }
+    /// Parses one brick per line, `x,y,z~x,y,z` from end to end.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut id = 0;
+        let mut bricks = parse::lines_with(Day22::DAY, input, |line| {
+            id += 1;
+            Brick::parse(line, id - 1)
+        })?;
+        bricks.sort_by_key(|brick| brick.start.z);
+
+        Ok(Wall::new(bricks))
+    }
+
+    fn droped_bricks(&self) -> Vec<Brick> {
+        let mut result = Vec::new();
+        self.bricks.iter().for_each(|brick| {
+            let mut new_brick = brick.clone();
+            let lowest = new_brick.hiegs_z_to_drop(&result);
+            let lenght = new_brick.end.z - new_brick.start.z;
+            new_brick.start.z = lowest + 1;
+            new_brick.end.z = new_brick.start.z + lenght;
+            result.push(new_brick);
+        });
+        result
+    }
+}
+
+/// Bricks that can be disintegrated without any other brick falling.
+pub fn safe_to_disintegrate(wall: &Wall) -> usize {
+    let droped = wall.droped_bricks();
+    let map_under: HashMap<usize, Vec<usize>> = droped
+        .iter()
+        .map(|brick| {
+            let under = brick.ids_under(&droped);
+            (brick.id, under)
+        })
+        .collect();
+    let map_above: HashMap<usize, Vec<usize>> = droped
+        .iter()
+        .map(|brick| {
+            let above = brick.ids_above(&droped);
+            (brick.id, above)
+        })
+        .collect();
+    let mut removable = map_under.iter().filter(|(_, under)| under.len() > 1).fold(
+        HashSet::new(),
+        |mut acc, (_, under)| {
+            under.iter().for_each(|id| {
+                acc.insert(*id);
+            });
+            acc
+        },
+    );
+    map_under
+        .iter()
+        .filter(|(_, under)| under.len() == 1)
+        .for_each(|(_, under)| {
+            removable.remove(&under[0]);
+        });
+    map_above
+        .iter()
+        .filter(|(_, above)| above.is_empty())
+        .map(|(id, _)| id)
+        .for_each(|id| {
+            removable.insert(*id);
+        });
+    removable.len()
+}
+
+/// Sum over every brick of how many others would fall if it were
+/// disintegrated.
+pub fn chain_reaction(wall: &Wall) -> usize {
+    let droped = wall.droped_bricks();
+    let map_under: HashMap<usize, Vec<usize>> = droped
+        .iter()
+        .map(|brick| {
+            let under = brick.ids_under(&droped);
+            (brick.id, under)
+        })
+        .collect();
+    let map_above: HashMap<usize, Vec<usize>> = droped
+        .iter()
+        .map(|brick| {
+            let above = brick.ids_above(&droped);
+            (brick.id, above)
+        })
+        .collect();
+    let mut count = 0;
+    droped.iter().for_each(|brick| {
+        let mut desintegrated = HashSet::new();
+        desintegrated.insert(brick.id);
+
+        let above = &map_above[&brick.id];
+        let mut queue = VecDeque::new();
+        above.iter().for_each(|id| {
+            queue.push_back(*id);
+        });
+        while let Some(id) = queue.pop_front() {
+            let under = &map_under[&id];
+            if under.iter().all(|id| desintegrated.contains(id)) {
+                desintegrated.insert(id);
+                let above = &map_above[&id];
+                above.iter().for_each(|id| {
+                    queue.push_back(*id);
+                });
+            }
+        }
+        count += desintegrated.len() - 1;
+    });
+
+    count
+}
+
+/// The [`Solution`] for day 22, the input is the wall of bricks.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input<'a> = Wall;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Wall, Error> {
+        Wall::parse(input)
+    }
+
+    fn part1(wall: &Wall) -> Result<usize, Error> {
+        Ok(safe_to_disintegrate(wall))
+    }
+
+    fn part2(wall: &Wall) -> Result<usize, Error> {
+        Ok(chain_reaction(wall))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day22::part1(&Day22::parse(input).unwrap()).unwrap(), 5);
+    }
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day22::part2(&Day22::parse(input).unwrap()).unwrap(), 7);
+    }
+    #[test]
+    fn test_errores() {
+        let error = Day22::parse("1,0,1~1,2,1\n0,0,2~2,x,2").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert!(Day22::parse("1,0,1~1,2").is_err());
+        assert!(Day22::parse("1,0,3~1,2,1").is_err());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day22>(include_str!("input_test.txt"));
+    }
+}
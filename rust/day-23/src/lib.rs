@@ -0,0 +1,361 @@
+//! Day 23: A Long Walk. The longest hike through the forest that never steps
+//! on the same tile twice.
+
+#![warn(missing_docs)]
+
+use aoc_core::{
+    grid::Grid,
+    render::{Frame, Rgb},
+    Error, Point, Solution, Visualize,
+};
+
+/// The trail map, from the gap in the top row to the one in the bottom row.
+#[derive(Debug)]
+pub struct Map {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+/// A walk through the map: each point and the steps from the one before.
+type Route = Vec<(Point, usize)>;
+
+/// The branching points of the map, the start and the end, numbered, with
+/// the corridors between them.
+struct Branches {
+    /// The number of each branching point, `None` for the other cells.
+    nodes: Grid<Option<usize>>,
+    /// Where each branching point is.
+    points: Vec<Point>,
+    /// The branching points each one leads to and the steps to get there.
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl Map {
+    /// Parses paths `.`, forest `#` and slopes `<>^v`.
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let map = Grid::parse(Day23::DAY, input, ".#<>^v")?;
+
+        let max_x = map.width() as i64 - 1;
+        let max_y = map.height() as i64 - 1;
+        let start = Point::new(1, 0);
+        let end = Point::new(max_x - 1, max_y);
+        for (point, nombre) in [(start, "inicio"), (end, "final")] {
+            if map.get(point) != Some(&'.') {
+                return Err(
+                    Error::parse(Day23::DAY, format!("el {} no es un camino", nombre))
+                        .at(point.y.max(0) as usize + 1, point.x.max(0) as usize + 1),
+                );
+            }
+        }
+        Ok(Self { map, start, end })
+    }
+
+    fn neighbors(&self, point: &Point) -> Vec<Point> {
+        let left = Point::new(-1, 0);
+        let right = Point::new(1, 0);
+        let up = Point::new(0, -1);
+        let down = Point::new(0, 1);
+        let directions = vec![left, right, up, down];
+        match self.map.get(*point) {
+            Some('>') => return vec![*point + right],
+            Some('<') => return vec![*point + left],
+            Some('^') => return vec![*point + up],
+            Some('v') => return vec![*point + down],
+            _ => {}
+        }
+        let mut neighbors = Vec::new();
+        for direction in directions {
+            let neighbor = *point + direction;
+            //if neighbot is not valid, skip
+            match self.map.get(neighbor) {
+                None => continue,
+                Some(c) => match (c, direction.x, direction.y) {
+                    ('#', _, _) => continue,
+                    ('>', -1, 0) => continue,
+                    ('<', 1, 0) => continue,
+                    ('^', 0, 1) => continue,
+                    ('v', 0, -1) => continue,
+                    _ => neighbors.push(neighbor),
+                },
+            }
+        }
+        neighbors
+    }
+
+    /// Steps of the longest hike going down slopes only in their direction,
+    /// `None` if the end can't be reached.
+    pub fn longest_path(&self) -> Option<usize> {
+        let mut ends = Vec::new();
+        let mut visited = self.map.map(|_| false);
+        self.longest_path_dfs(self.start, &mut visited, 0, &mut ends);
+        ends.iter().max().copied()
+    }
+
+    fn longest_path_dfs(
+        &self,
+        point: Point,
+        visited: &mut Grid<bool>,
+        steps: usize,
+        ends: &mut Vec<usize>,
+    ) {
+        if point == self.end {
+            ends.push(steps);
+            return;
+        }
+        if visited[point] {
+            return;
+        }
+        visited[point] = true;
+        for neighbor in self.neighbors(&point) {
+            if visited[neighbor] {
+                continue;
+            }
+            self.longest_path_dfs(neighbor, visited, steps + 1, ends);
+        }
+        visited[point] = false;
+    }
+
+    fn neighbors_part2(&self, point: &Point) -> Vec<Point> {
+        self.map
+            .neighbors(*point)
+            .filter(|neighbor| self.map[*neighbor] != '#')
+            .collect()
+    }
+
+    //find distances from branching points to other branching points
+    fn find_branching_distances(&self) -> Branches {
+        //number the branching points, start and end
+        let mut nodes = self.map.map(|_| None);
+        let mut points = Vec::new();
+        for (point, c) in self.map.iter() {
+            if *c != '#' && self.neighbors_part2(&point).len() != 2 {
+                nodes[point] = Some(points.len());
+                points.push(point);
+            }
+        }
+        let mut edges = vec![Vec::new(); points.len()];
+        for (node, point) in points.iter().enumerate() {
+            for mut neighor in self.neighbors_part2(point) {
+                let mut prev = *point;
+                let mut dist = 0;
+                loop {
+                    dist += 1;
+                    let neighbors = self.neighbors_part2(&neighor);
+                    let neighbors = neighbors.iter().filter(|n| **n != prev).collect::<Vec<_>>();
+                    if neighbors.len() != 1 {
+                        if let Some(to) = nodes[neighor] {
+                            edges[node].push((to, dist));
+                        }
+                        break;
+                    }
+                    prev = neighor;
+                    neighor = *neighbors[0];
+                }
+            }
+        }
+        Branches {
+            nodes,
+            points,
+            edges,
+        }
+    }
+
+    /// Like [`Map::longest_path`] with slopes climbed like any other path.
+    pub fn longest_path_part2(&self) -> Option<usize> {
+        let branches = self.find_branching_distances();
+        let (start, end) = (branches.nodes[self.start]?, branches.nodes[self.end]?);
+        let mut visited = vec![false; branches.points.len()];
+        let mut longest = None;
+        Self::longest_path_part2_dfs(start, end, &mut visited, 0, &mut longest, &branches);
+        longest
+    }
+
+    fn longest_path_part2_dfs(
+        node: usize,
+        end: usize,
+        visited: &mut [bool],
+        steps: usize,
+        longest: &mut Option<usize>,
+        branches: &Branches,
+    ) {
+        if node == end {
+            *longest = (*longest).max(Some(steps));
+            return;
+        }
+        visited[node] = true;
+        for &(next, dist) in &branches.edges[node] {
+            if !visited[next] {
+                Self::longest_path_part2_dfs(next, end, visited, steps + dist, longest, branches);
+            }
+        }
+        visited[node] = false;
+    }
+
+    /// Longest walk from the start to the end moving along `edges`.
+    fn longest_route(
+        &self,
+        edges: &dyn Fn(Point) -> Vec<(Point, usize)>,
+    ) -> Option<(Route, usize)> {
+        let mut route = vec![(self.start, 0)];
+        let mut visited = self.map.map(|_| false);
+        let mut best = None;
+        self.longest_route_dfs(edges, &mut route, &mut visited, 0, &mut best);
+        best
+    }
+
+    fn longest_route_dfs(
+        &self,
+        edges: &dyn Fn(Point) -> Vec<(Point, usize)>,
+        route: &mut Route,
+        visited: &mut Grid<bool>,
+        steps: usize,
+        best: &mut Option<(Route, usize)>,
+    ) {
+        let Some(&(point, _)) = route.last() else {
+            return;
+        };
+        if point == self.end {
+            if best.as_ref().is_none_or(|(_, longest)| *longest < steps) {
+                *best = Some((route.clone(), steps));
+            }
+            return;
+        }
+        visited[point] = true;
+        for (next, dist) in edges(point) {
+            if !visited[next] {
+                route.push((next, dist));
+                self.longest_route_dfs(edges, route, visited, steps + dist, best);
+                route.pop();
+            }
+        }
+        visited[point] = false;
+    }
+
+    /// The cells of a corridor `dist` steps long from branching point `from`
+    /// to `to`, without `from`.
+    fn corridor(&self, from: Point, to: Point, dist: usize) -> Vec<Point> {
+        for first in self.neighbors_part2(&from) {
+            let (mut prev, mut cells) = (from, vec![first]);
+            while let Some(&current) = cells.last().filter(|_| cells.len() < dist) {
+                let next = self.neighbors_part2(&current);
+                let mut next = next.into_iter().filter(|n| *n != prev);
+                match (next.next(), next.next()) {
+                    (Some(next), None) => cells.push(next),
+                    _ => break,
+                }
+                prev = current;
+            }
+            if cells.len() == dist && cells.last() == Some(&to) {
+                return cells;
+            }
+        }
+        Vec::new()
+    }
+}
+
+/// The [`Solution`] for day 23, the input is the trail map.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input<'a> = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map, Error> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Map) -> Result<usize, Error> {
+        map.longest_path()
+            .ok_or_else(|| Error::solve(Self::DAY, "no hay camino a la meta"))
+    }
+
+    fn part2(map: &Map) -> Result<usize, Error> {
+        map.longest_path_part2()
+            .ok_or_else(|| Error::solve(Self::DAY, "no hay camino a la meta"))
+    }
+}
+
+// The longest hike of each part, part 2 with the branching points it
+// jumps between.
+impl Visualize for Day23 {
+    fn frames(map: &Map) -> Result<Vec<Frame>, Error> {
+        let no_path = || Error::solve(Self::DAY, "no hay camino a la meta");
+        let ends = [map.start, map.end];
+
+        let slopes = |point: Point| map.neighbors(&point).into_iter().map(|n| (n, 1)).collect();
+        let (route, steps) = map.longest_route(&slopes).ok_or_else(no_path)?;
+        let part1 = Frame::new(format!("parte 1: {} pasos", steps), &map.map)
+            .overlay(route.iter().map(|(point, _)| *point), Rgb::PATH)
+            .overlay(ends, Rgb::START);
+
+        let branches = map.find_branching_distances();
+        let edges = |point: Point| match branches.nodes[point] {
+            Some(node) => branches.edges[node]
+                .iter()
+                .map(|&(next, dist)| (branches.points[next], dist))
+                .collect(),
+            None => Vec::new(),
+        };
+        let (route, steps) = map.longest_route(&edges).ok_or_else(no_path)?;
+        let cells = route
+            .windows(2)
+            .flat_map(|hop| map.corridor(hop[0].0, hop[1].0, hop[1].1));
+        let part2 = Frame::new(format!("parte 2: {} pasos", steps), &map.map)
+            .overlay(cells.collect::<Vec<_>>(), Rgb::PATH)
+            .overlay(branches.points.iter().copied(), Rgb::LOOP)
+            .overlay(ends, Rgb::START);
+        Ok(vec![part1, part2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("input_test.txt");
+        let map = Map::new(input).unwrap();
+        assert_eq!(map.longest_path(), Some(94));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("input_test.txt");
+        let map = Map::new(input).unwrap();
+        assert_eq!(map.longest_path_part2(), Some(154));
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Map::new("#.#\n#.#\n##.").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(2)));
+        let map = Map::new("#.###\n#.#.#\n###.#").unwrap();
+        assert!(Day23::part1(&map).is_err());
+        assert!(Day23::part2(&map).is_err());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day23>(include_str!("input_test.txt"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let map = Map::new(include_str!("input_test.txt")).unwrap();
+        let frames = Day23::frames(&map).unwrap();
+        let path = |frame: &Frame| {
+            let points = map.map.points();
+            points.filter(|p| frame.color(*p) == Rgb::PATH).count()
+        };
+        assert_eq!(frames[0].title, "parte 1: 94 pasos");
+        assert_eq!(path(&frames[0]), 94 - 1);
+        assert_eq!(frames[1].title, "parte 2: 154 pasos");
+        assert_eq!(frames[1].color(map.end), Rgb::START);
+    }
+}
@@ -0,0 +1,257 @@
+//! Day 24: Never Tell Me The Odds. Hailstones flying in straight lines and
+//! the rock throw that hits every one of them.
+
+#![warn(missing_docs)]
+
+use std::fmt;
+
+use aoc_core::{parse, Error, Solution};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space1},
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Parser,
+};
+
+use nalgebra::{Matrix6x1, Matrix6, RowVector6};
+#[derive(Debug)]
+enum Intersection {
+    Point(Point),
+    All,
+}
+#[derive(Debug)]
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+#[derive(Debug)]
+struct Velocity {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+/// A hailstone, where it is and how far it moves each nanosecond.
+#[derive(Debug)]
+pub struct Hail {
+    position: Point,
+    velocity: Velocity,
+}
+
+impl Hail {
+    /// A hailstone at `(x, y, z)` moving `(dx, dy, dz)` each nanosecond.
+    pub fn new((x, y, z): (i64, i64, i64), (dx, dy, dz): (i64, i64, i64)) -> Self {
+        Hail {
+            position: Point { x, y, z },
+            velocity: Velocity { x: dx, y: dy, z: dz },
+        }
+    }
+
+    fn intersection_xy(&self, other: &Hail) -> Option<Intersection> {
+        let slope_self = self.velocity.y as f64 / self.velocity.x as f64;
+        let slope_other = other.velocity.y as f64 / other.velocity.x as f64;
+        let intersept_self = self.position.y as f64 - slope_self * self.position.x as f64;
+        let intersept_other = other.position.y as f64 - slope_other * other.position.x as f64;
+        if slope_self == slope_other && intersept_self == intersept_other {
+            return Some(Intersection::All);
+        } else if slope_self == slope_other {
+            return None;
+        }
+        let x = (intersept_other - intersept_self) / (slope_self - slope_other);
+        let y = slope_self * x + intersept_self;
+        Some(Intersection::Point(Point {
+            x: x as i64,
+            y: y as i64,
+            z: 0,
+        }))
+    }
+
+    fn intersects_in_the_past_xy(&self, point: &Point) -> bool {
+        let x = point.x - self.position.x;
+        let y = point.y - self.position.y;
+        let x = x as f64 / self.velocity.x as f64;
+        let y = y as f64 / self.velocity.y as f64;
+        x < 0.0 && y < 0.0
+    }
+}
+
+impl fmt::Display for Hail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (p, v) = (&self.position, &self.velocity);
+        write!(f, "{}, {}, {} @ {}, {}, {}", p.x, p.y, p.z, v.x, v.y, v.z)
+    }
+}
+
+fn point(input: &str) -> IResult<&str, Point> {
+    let (input, x) = complete::i64(input)?;
+    let (input, _) = terminated(tag(","), space1)(input)?;
+    let (input, y) = complete::i64(input)?;
+    let (input, _) = terminated(tag(","), space1)(input)?;
+    let (input, z) = complete::i64(input)?;
+    Ok((input, Point { x, y, z }))
+}
+
+fn velocity(input: &str) -> IResult<&str, Velocity> {
+    let (input, x) = complete::i64(input)?;
+    let (input, _) = terminated(tag(","), space1)(input)?;
+    let (input, y) = complete::i64(input)?;
+    let (input, _) = terminated(tag(","), space1)(input)?;
+    let (input, z) = complete::i64(input)?;
+    Ok((input, Velocity { x, y, z }))
+}
+
+fn hail(input: &str) -> IResult<&str, Hail> {
+    separated_pair(point, delimited(space1, tag("@"), space1), velocity)
+        .map(|(position, velocity)| Hail { position, velocity })
+        .parse(input)
+}
+
+/// Pairs of hailstones whose paths cross inside the test area, ignoring z.
+pub fn intersections_in_area(hails: &[Hail], min: i64, max: i64) -> usize {
+    hails
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(hail1, hail2)| {
+            let intersection = hail1.intersection_xy(hail2);
+            match intersection {
+                Some(Intersection::Point(ref point)) => {
+                    //println!("{:?} {:?} {:?}", hail1, hail2, point);
+                    if !hail1.intersects_in_the_past_xy(point)
+                        && !hail2.intersects_in_the_past_xy(point)
+                        && point.x >= min
+                        && point.x <= max
+                        && point.y >= min
+                        && point.y <= max
+                    {
+                        Some(intersection)
+                    } else {
+                        None
+                    }
+                }
+                Some(Intersection::All) => Some(intersection),
+                None => None,
+            }
+        })
+        .count()
+}
+
+/// Sum of the coordinates of the position a rock has to be thrown from to
+/// hit every hailstone, worked out from the first three.
+pub fn rock_throw(hails: &[Hail]) -> Result<i128, Error> {
+    if hails.len() < 3 {
+        return Err(Error::solve(Day24::DAY, "se necesitan al menos 3 granizos"));
+    }
+    let p0 = &hails[0].position;
+    let p1 = &hails[1].position;
+    let p2 = &hails[2].position;
+    let v0 = &hails[0].velocity;
+    let v1 = &hails[1].velocity;
+    let v2 = &hails[2].velocity;
+
+    let b = Matrix6x1::from_row_slice(&[
+        ((p0.y as i128 * v0.x as i128 - p1.y as i128 * v1.x as i128)
+            - (p0.x as i128 * v0.y as i128 - p1.x as i128 * v1.y as i128)) as f64,
+        ((p0.y as i128 * v0.x as i128 - p2.y as i128 * v2.x as i128)
+            - (p0.x as i128 * v0.y as i128 - p2.x as i128 * v2.y as i128)) as f64,
+        ((p0.z as i128 * v0.x as i128 - p1.z as i128 * v1.x as i128)
+            - (p0.x as i128 * v0.z as i128 - p1.x as i128 * v1.z as i128)) as f64,
+        ((p0.z as i128 * v0.x as i128 - p2.z as i128 * v2.x as i128)
+            - (p0.x as i128 * v0.z as i128 - p2.x as i128 * v2.z as i128)) as f64,
+        ((p0.z as i128 * v0.y as i128 - p1.z as i128 * v1.y as i128)
+            - (p0.y as i128 * v0.z as i128 - p1.y as i128 * v1.z as i128)) as f64,
+        ((p0.z as i128 * v0.y as i128 - p2.z as i128 * v2.y as i128)
+            - (p0.y as i128 * v0.z as i128 - p2.y as i128 * v2.z as i128)) as f64,
+    ]);
+
+    let a = Matrix6::from_rows(&[
+        RowVector6::new(v1.y as f64 - v0.y as f64, v0.x as f64  - v1.x as f64, 0.0, p0.y as f64 - p1.y as f64, p1.x as f64 - p0.x as f64, 0.0),
+        RowVector6::new(v2.y as f64 - v0.y as f64, v0.x as f64 - v2.x as f64, 0.0, p0.y as f64 - p2.y as f64, p2.x as f64 - p0.x as f64, 0.0),
+        RowVector6::new(v1.z as f64 - v0.z as f64, 0.0, v0.x as f64 - v1.x as f64, p0.z as f64 - p1.z as f64, 0.0, p1.x as f64 - p0.x as f64),
+        RowVector6::new(v2.z as f64 - v0.z as f64, 0.0, v0.x as f64 - v2.x as f64, p0.z as f64 - p2.z as f64, 0.0, p2.x as f64 - p0.x as f64),
+        RowVector6::new(0.0, v1.z as f64 - v0.z as f64, v0.y as f64 - v1.y as f64, 0.0, p0.z as f64 - p1.z as f64, p1.y as f64 - p0.y as f64),
+        RowVector6::new(0.0, v2.z as f64 - v0.z as f64, v0.y as f64 - v2.y as f64, 0.0, p0.z as f64 - p2.z as f64, p2.y as f64 - p0.y as f64),
+    ]);
+
+    let r = a
+        .lu()
+        .solve(&b)
+        .ok_or_else(|| Error::solve(Day24::DAY, "el sistema no tiene solucion unica"))?;
+    Ok(r[0] as i128 + r[1] as i128 + r[2] as i128)
+    
+
+}
+
+/// Parses the hailstones and writes them back, for the fuzz targets.
+pub fn rewrite(input: &str) -> Result<String, Error> {
+    let hails = Day24::parse(input)?;
+    Ok(hails.iter().map(|h| format!("{}\n", h)).collect())
+}
+
+/// The [`Solution`] for day 24, the input is the list of hailstones.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input<'a> = Vec<Hail>;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Vec<Hail>, Error> {
+        parse::lines(Self::DAY, input, hail)
+    }
+
+    fn part1(hails: &Vec<Hail>) -> Result<usize, Error> {
+        Ok(intersections_in_area(hails, 200000000000000, 400000000000000))
+    }
+
+    fn part2(hails: &Vec<Hail>) -> Result<i128, Error> {
+        rock_throw(hails)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = include_str!("input_test.txt");
+        let hails = Day24::parse(input).unwrap();
+        assert_eq!(intersections_in_area(&hails, 7, 27), 2);
+    }
+
+    #[test]
+    fn test_hail() {
+        let hails = [
+            Hail::new((19, 13, 30), (-2, 1, -2)),
+            Hail::new((18, 19, 22), (-1, -1, -2)),
+        ];
+        assert_eq!(hails[0].to_string(), "19, 13, 30 @ -2, 1, -2");
+        assert_eq!(intersections_in_area(&hails, 7, 27), 1);
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day24::parse("19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(7)));
+        let hails = Day24::parse("19, 13, 30 @ -2, 1, -2").unwrap();
+        assert!(Day24::part2(&hails).is_err());
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "19,  13, 30 @  -2, 1, -2\n+18, 19, 22 @ -1, -1, -2";
+        let expected = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n";
+        assert_eq!(rewrite(input).unwrap(), expected);
+        parse::round_trip(input, rewrite);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day24>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,136 @@
+//! Day 25: Snowverload. Cutting three wires to split the components in two
+//! groups.
+
+#![warn(missing_docs)]
+
+use std::collections::{BinaryHeap, HashMap};
+
+use aoc_core::{parse, solution::NoAnswer, Error, Solution};
+
+/// Stoer-Wagner, returns the weight of the minimum cut and the nodes on one
+/// side, `None` for graphs with fewer than two nodes.
+pub fn stoer_wagner_min_cut(mut adjacency: Graph) -> Option<(usize, Vec<usize>)> {
+    let mut groups = (0..adjacency.len()).map(|node| vec![node]).collect::<Vec<_>>();
+    let mut active = (0..adjacency.len()).collect::<Vec<_>>();
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    while active.len() > 1 {
+        //maximum adjacency order, the last two nodes added are merged
+        let mut weights = vec![0; adjacency.len()];
+        let mut added = vec![false; adjacency.len()];
+        let mut frontier = BinaryHeap::from([(0, active[0])]);
+        let (mut previous, mut last) = (active[0], active[0]);
+        while let Some((weight, node)) = frontier.pop() {
+            if added[node] || weight != weights[node] {
+                continue;
+            }
+            added[node] = true;
+            previous = last;
+            last = node;
+            for (&neighbor, &edge) in &adjacency[node] {
+                if !added[neighbor] {
+                    weights[neighbor] += edge;
+                    frontier.push((weights[neighbor], neighbor));
+                }
+            }
+        }
+        let cut = weights[last];
+        if best.as_ref().is_none_or(|(best_cut, _)| cut < *best_cut) {
+            best = Some((cut, groups[last].clone()));
+        }
+        let edges = std::mem::take(&mut adjacency[last]);
+        for (neighbor, edge) in edges {
+            adjacency[neighbor].remove(&last);
+            if neighbor != previous {
+                *adjacency[previous].entry(neighbor).or_default() += edge;
+                *adjacency[neighbor].entry(previous).or_default() += edge;
+            }
+        }
+        let merged = std::mem::take(&mut groups[last]);
+        groups[previous].extend(merged);
+        active.retain(|&node| node != last);
+    }
+    best
+}
+
+/// Weighted adjacency list, nodes are numbered in order of appearance.
+pub type Graph = Vec<HashMap<usize, usize>>;
+
+/// Parses one `node: neighbors` line per component, every wire of weight 1.
+pub fn parse_graph(input: &str) -> Result<Graph, Error> {
+    let mut adjacency: Graph = Vec::new();
+    let mut nodes = HashMap::new();
+    let mut index = |name, adjacency: &mut Graph| {
+        *nodes.entry(name).or_insert_with(|| {
+            adjacency.push(HashMap::new());
+            adjacency.len() - 1
+        })
+    };
+    parse::lines_with(Day25::DAY, input, |line| {
+        let (node, edges) = line.split_once(": ").ok_or("se esperaba `nodo: vecinos`")?;
+        if node.is_empty() || edges.trim().is_empty() {
+            return Err(format!("linea incompleta: {}", line));
+        }
+        let node = index(node, &mut adjacency);
+        for edge in edges.split_whitespace() {
+            let edge = index(edge, &mut adjacency);
+            *adjacency[node].entry(edge).or_default() += 1;
+            *adjacency[edge].entry(node).or_default() += 1;
+        }
+        Ok(())
+    })?;
+    Ok(adjacency)
+}
+
+/// The [`Solution`] for day 25, the input is the wiring graph.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input<'a> = Graph;
+    type Part1 = usize;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Graph, Error> {
+        parse_graph(input)
+    }
+
+    //solve with minimum cut
+    fn part1(adjacency: &Graph) -> Result<usize, Error> {
+        let total = adjacency.len();
+        match stoer_wagner_min_cut(adjacency.clone()) {
+            None => Err(Error::solve(Self::DAY, "el grafo no tiene corte")),
+            Some((_, partition)) => Ok(partition.len() * (total - partition.len())),
+        }
+    }
+
+    fn part2(_: &Graph) -> Result<NoAnswer, Error> {
+        Err(Error::solve(Self::DAY, "el dia 25 no tiene segunda parte"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::solution::check_line_endings;
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = include_str!("input_test.txt");
+        assert_eq!(Day25::part1(&Day25::parse(input).unwrap()).unwrap(), 54);
+    }
+
+    #[test]
+    fn test_errores() {
+        let error = Day25::parse("jqt: rhn xhk\nrsh frs").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        let graph = Day25::parse("jqt: rhn").unwrap();
+        assert!(Day25::part1(&graph).is_ok());
+        assert!(Day25::part1(&Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_line_endings() {
+        check_line_endings::<Day25>(include_str!("input_test.txt"));
+    }
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+use day_02::{format, Format};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    aoc_core::parse::round_trip(input, day_02::rewrite);
+    for from in [Format::Json, Format::Csv] {
+        aoc_core::parse::round_trip(input, |input| {
+            let games = format::read(input, from)?;
+            let text = format::write(&games, Format::Text);
+            let again = format::read(&text, Format::Text)?;
+            Ok(format::write(&again, from))
+        });
+    }
+});